@@ -0,0 +1,269 @@
+use ggez::audio::{self, SoundSource};
+
+use std::any;
+use std::collections;
+
+/// How many copies of the same sound can play at the same time. Playing the sound again while
+/// every copy is playing is skipped.
+pub const MAX_CONCURRENT_SOUNDS: usize = 3;
+
+/// How many of the last sounds that were played the null backend keeps.
+pub const MAX_RECORDED_SOUNDS: usize = 64;
+
+/// Where the game's audio goes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Output {
+    /// The audio is played on the default sound device, through the audio module of ggez.
+    Device,
+    /// The audio is not played, for machines without a sound device or when it is turned off.
+    Null,
+}
+
+impl Output {
+    /// Create the backend that plays audio to the output.
+    pub fn backend(self) -> Box<dyn AudioBackend> {
+        match self {
+            Output::Device => Box::new(DeviceAudio::default()),
+            Output::Null => Box::new(NullAudio::default()),
+        }
+    }
+}
+
+/// Something that plays the game's sounds and music. The volumes are given from silent at 0 to
+/// full volume at 1.
+pub trait AudioBackend: Send + Sync {
+    /// Load the sound at the path so that it can be played.
+    fn add_sound(&mut self, ctx: &mut ggez::Context, path: &str) -> ggez::GameResult;
+
+    /// Play the sound at the path, unless every copy of it is already playing. Sounds that were
+    /// not added are skipped.
+    fn play_sound(&mut self, path: &str, volume: f32) -> ggez::GameResult;
+
+    /// Load the music at the path, which starts over once it ends.
+    fn add_music(&mut self, ctx: &mut ggez::Context, path: &str) -> ggez::GameResult;
+
+    /// Start the music from its beginning.
+    fn play_music(&mut self, volume: f32) -> ggez::GameResult;
+
+    /// Change the volume of the music that is playing.
+    fn set_music_volume(&mut self, volume: f32);
+
+    fn as_any(&self) -> &dyn any::Any;
+}
+
+/// Plays the audio on the sound device. Every sound is loaded as a few sources that can play at
+/// the same time, which limits how many copies of the sound are heard at once. The music is
+/// decoded while it plays.
+#[derive(Default)]
+pub struct DeviceAudio {
+    sounds: collections::HashMap<String, Vec<audio::Source>>,
+    music: Option<audio::Source>,
+}
+
+impl AudioBackend for DeviceAudio {
+    fn add_sound(&mut self, ctx: &mut ggez::Context, path: &str) -> ggez::GameResult {
+        let data = audio::SoundData::new(ctx, path)?;
+        let sources = (0..MAX_CONCURRENT_SOUNDS)
+            .map(|_| audio::Source::from_data(ctx, data.clone()))
+            .collect::<ggez::GameResult<Vec<_>>>()?;
+        self.sounds.insert(path.to_string(), sources);
+        Ok(())
+    }
+
+    fn play_sound(&mut self, path: &str, volume: f32) -> ggez::GameResult {
+        let idle = self
+            .sounds
+            .get_mut(path)
+            .and_then(|sources| sources.iter_mut().find(|source| !source.playing()));
+        match idle {
+            Some(source) => {
+                source.set_volume(volume);
+                source.play()
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn add_music(&mut self, ctx: &mut ggez::Context, path: &str) -> ggez::GameResult {
+        let mut music = audio::Source::new(ctx, path)?;
+        music.set_repeat(true);
+        self.music = Some(music);
+        Ok(())
+    }
+
+    fn play_music(&mut self, volume: f32) -> ggez::GameResult {
+        match &mut self.music {
+            Some(music) => {
+                music.set_volume(volume);
+                music.play()
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn set_music_volume(&mut self, volume: f32) {
+        if let Some(music) = &mut self.music {
+            music.set_volume(volume);
+        }
+    }
+
+    fn as_any(&self) -> &dyn any::Any {
+        self
+    }
+}
+
+/// Plays nothing, but records the sounds that would have played along with their volumes, so
+/// that the game can run and be checked without a sound device. Every sound that is played is
+/// recorded, whether it was added or not, and the limit on concurrent sounds does not apply. Only
+/// the last sounds are kept, see [`MAX_RECORDED_SOUNDS`].
+#[derive(Debug, Default)]
+pub struct NullAudio {
+    played: collections::VecDeque<(String, f32)>,
+    music: Option<(String, f32)>,
+    is_music_playing: bool,
+}
+
+impl NullAudio {
+    /// Get the last sounds that were played, in order, with their volumes.
+    pub fn played(&self) -> impl ExactSizeIterator<Item = &(String, f32)> {
+        self.played.iter()
+    }
+
+    /// Get the music that is playing, with its volume.
+    pub fn music(&self) -> Option<(&str, f32)> {
+        match &self.music {
+            Some((path, volume)) if self.is_music_playing => Some((path, *volume)),
+            _ => None,
+        }
+    }
+}
+
+impl AudioBackend for NullAudio {
+    /// The sound is not loaded, but it must exist like on the sound device.
+    fn add_sound(&mut self, ctx: &mut ggez::Context, path: &str) -> ggez::GameResult {
+        if ggez::filesystem::exists(ctx, path) {
+            Ok(())
+        } else {
+            Err(ggez::GameError::ResourceLoadError(format!(
+                "Could not find the sound {}",
+                path
+            )))
+        }
+    }
+
+    fn play_sound(&mut self, path: &str, volume: f32) -> ggez::GameResult {
+        if self.played.len() == MAX_RECORDED_SOUNDS {
+            self.played.pop_front();
+        }
+        self.played.push_back((path.to_string(), volume));
+        Ok(())
+    }
+
+    fn add_music(&mut self, ctx: &mut ggez::Context, path: &str) -> ggez::GameResult {
+        self.add_sound(ctx, path)?;
+        self.music = Some((path.to_string(), 0.0));
+        Ok(())
+    }
+
+    fn play_music(&mut self, volume: f32) -> ggez::GameResult {
+        self.is_music_playing = self.music.is_some();
+        self.set_music_volume(volume);
+        Ok(())
+    }
+
+    fn set_music_volume(&mut self, volume: f32) {
+        if let Some((_, music_volume)) = &mut self.music {
+            *music_volume = volume;
+        }
+    }
+
+    fn as_any(&self) -> &dyn any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game;
+    use crate::level;
+    use crate::resources;
+
+    /// Play the actions in a game of the level without a window, where the audio goes to the
+    /// null backend at the given volume, then get the sounds that were played.
+    fn played_sounds(
+        xsb: &str,
+        volume: resources::Volume,
+        actions: &[resources::Action],
+    ) -> Vec<(String, f32)> {
+        let mut game = game::Headless::new(&level::Level::from_test_xsb(xsb));
+        let mut audio_store = resources::AudioStore::new(Output::Null);
+        audio_store.set_volume(volume);
+        game.resources.insert(audio_store);
+        for action in actions {
+            game.queue(*action);
+            game.tick(4);
+        }
+        let audio_store = game
+            .resources
+            .get::<resources::AudioStore>()
+            .expect("The audio store is a resource");
+        audio_store
+            .backend::<NullAudio>()
+            .expect("The audio goes to the null backend")
+            .played()
+            .cloned()
+            .collect()
+    }
+
+    #[test]
+    fn gameplay_events_play_their_sounds() {
+        let played = played_sounds(
+            "######\n#@$.*#\n######\n",
+            resources::Volume {
+                effects: 0.5,
+                ..resources::Volume::default()
+            },
+            &[resources::Action::MoveRight, resources::Action::MoveRight],
+        );
+        assert_eq!(
+            played,
+            vec![
+                ("/sounds/correct.wav".to_string(), 0.5),
+                ("/sounds/wall.wav".to_string(), 0.5),
+            ]
+        );
+    }
+
+    #[test]
+    fn muted_gameplay_events_are_silent() {
+        let played = played_sounds(
+            "######\n#@$.*#\n######\n",
+            resources::Volume {
+                is_muted: true,
+                ..resources::Volume::default()
+            },
+            &[resources::Action::MoveRight, resources::Action::MoveRight],
+        );
+        assert!(played.is_empty());
+    }
+
+    #[test]
+    fn null_audio_keeps_the_last_sounds() {
+        let mut audio = NullAudio::default();
+        for idx in 0..MAX_RECORDED_SOUNDS + 10 {
+            audio
+                .play_sound(&format!("/sounds/{}.wav", idx), 1.0)
+                .expect("The null backend plays every sound");
+        }
+        assert_eq!(audio.played().len(), MAX_RECORDED_SOUNDS);
+        assert_eq!(
+            audio.played().next().map(|(path, _)| path.as_str()),
+            Some("/sounds/10.wav")
+        );
+        assert_eq!(
+            audio.played().last().map(|(path, _)| path.clone()),
+            Some(format!("/sounds/{}.wav", MAX_RECORDED_SOUNDS + 9))
+        );
+    }
+}
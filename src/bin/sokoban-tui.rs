@@ -0,0 +1,244 @@
+use crossterm::{cursor, event, execute, queue, style, terminal};
+use ggez::input::keyboard;
+
+use std::error;
+use std::io::{self, Write};
+use std::time;
+
+use sokoban::components;
+use sokoban::game;
+use sokoban::level;
+use sokoban::resources;
+
+const LETTER_KEYS: [keyboard::KeyCode; 26] = [
+    keyboard::KeyCode::A,
+    keyboard::KeyCode::B,
+    keyboard::KeyCode::C,
+    keyboard::KeyCode::D,
+    keyboard::KeyCode::E,
+    keyboard::KeyCode::F,
+    keyboard::KeyCode::G,
+    keyboard::KeyCode::H,
+    keyboard::KeyCode::I,
+    keyboard::KeyCode::J,
+    keyboard::KeyCode::K,
+    keyboard::KeyCode::L,
+    keyboard::KeyCode::M,
+    keyboard::KeyCode::N,
+    keyboard::KeyCode::O,
+    keyboard::KeyCode::P,
+    keyboard::KeyCode::Q,
+    keyboard::KeyCode::R,
+    keyboard::KeyCode::S,
+    keyboard::KeyCode::T,
+    keyboard::KeyCode::U,
+    keyboard::KeyCode::V,
+    keyboard::KeyCode::W,
+    keyboard::KeyCode::X,
+    keyboard::KeyCode::Y,
+    keyboard::KeyCode::Z,
+];
+
+/// Run the game in the terminal. The same world, resources, and systems as the windowed game are
+/// used, only the input and rendering are replaced by the terminal.
+fn main() -> Result<(), Box<dyn error::Error>> {
+    let mut world = game::create_world(game::DEFAULT_MAP)?;
+    let mut resources = game::create_resources();
+    resources.insert(resources::AudioStore::default());
+    let mut schedule = game::create_schedule();
+
+    let mut stdout = io::stdout();
+    let _terminal = RawTerminal::enter(&mut stdout)?;
+    run(&mut stdout, &mut world, &mut resources, &mut schedule)
+}
+
+/// The terminal in raw mode on the alternate screen, which is put back the way it was when this
+/// is dropped, even when the game panics.
+struct RawTerminal;
+
+impl RawTerminal {
+    fn enter(stdout: &mut io::Stdout) -> crossterm::Result<Self> {
+        terminal::enable_raw_mode()?;
+        let terminal = Self;
+        execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(terminal)
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        // The terminal is restored as well as possible, there is nowhere left to report errors.
+        let _ = execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Read keys from the terminal, run the game's systems, and render the world until the player
+/// quits the game.
+fn run(
+    stdout: &mut io::Stdout,
+    world: &mut legion::World,
+    resources: &mut legion::Resources,
+    schedule: &mut legion::Schedule,
+) -> Result<(), Box<dyn error::Error>> {
+    let step = time::Duration::from_secs(1) / game::FPS;
+    let mut last_update = time::Instant::now();
+    let mut lag = time::Duration::ZERO;
+    loop {
+        if event::poll(step)? {
+            if let event::Event::Key(key) = event::read()? {
+                // Ctrl-C does not raise a signal in raw mode, so it is handled here.
+                if key.code == event::KeyCode::Char('c')
+                    && key.modifiers.contains(event::KeyModifiers::CONTROL)
+                {
+                    return Ok(());
+                }
+
+                let action = to_keycode(key.code).and_then(|keycode| {
+                    resources
+                        .get::<resources::InputBindings>()
+                        .and_then(|bindings| bindings.action(resources::Input::Key(keycode)))
+                });
+                match action {
+                    Some(resources::Action::Menu) => return Ok(()),
+                    Some(action) => game::perform(resources, action),
+                    None => {}
+                }
+            }
+        }
+
+        // The game is ticked at a fixed rate whatever the rate of the keys and the rendering.
+        let now = time::Instant::now();
+        lag += now - last_update;
+        last_update = now;
+        while lag >= step {
+            game::tick(world, resources, schedule);
+            lag -= step;
+        }
+        render(stdout, world, resources)?;
+    }
+}
+
+/// Translate a key from the terminal into the key code that is used by the input bindings.
+fn to_keycode(code: event::KeyCode) -> Option<keyboard::KeyCode> {
+    match code {
+        event::KeyCode::Up => Some(keyboard::KeyCode::Up),
+        event::KeyCode::Down => Some(keyboard::KeyCode::Down),
+        event::KeyCode::Left => Some(keyboard::KeyCode::Left),
+        event::KeyCode::Right => Some(keyboard::KeyCode::Right),
+        event::KeyCode::Home => Some(keyboard::KeyCode::Home),
+        event::KeyCode::End => Some(keyboard::KeyCode::End),
+        event::KeyCode::PageUp => Some(keyboard::KeyCode::PageUp),
+        event::KeyCode::PageDown => Some(keyboard::KeyCode::PageDown),
+        event::KeyCode::Esc => Some(keyboard::KeyCode::Escape),
+        event::KeyCode::Tab => Some(keyboard::KeyCode::Tab),
+        event::KeyCode::Char(c) if c.is_ascii_alphabetic() => {
+            Some(LETTER_KEYS[(c.to_ascii_lowercase() as u8 - b'a') as usize])
+        }
+        _ => None,
+    }
+}
+
+/// Draw the game map and the state of the game onto the terminal. Each tile is drawn with two
+/// characters, so that the map keeps its proportions.
+///
+/// # Examples
+///
+/// ```txt
+///     █ █ █ █ █ █ █
+/// █ █ █ · · · · · █
+/// █ · · · ■ · · · █
+/// █ · @ · · ○ · · █
+/// █ █ █ █ █ █ █ █ █
+///
+/// Playing
+/// Moves: 12
+/// ```
+fn render(
+    stdout: &mut io::Stdout,
+    world: &legion::World,
+    resources: &legion::Resources,
+) -> Result<(), Box<dyn error::Error>> {
+    let level = level::Level::from_world(world);
+    for y in 0..level.height() {
+        queue!(stdout, cursor::MoveTo(0, y as u16))?;
+        for x in 0..level.width() {
+            let tile = level.get(x, y);
+            let (symbol, color) = match tile {
+                level::Tile {
+                    terrain: level::Terrain::Void,
+                    ..
+                } => (' ', style::Color::Reset),
+                level::Tile {
+                    terrain: level::Terrain::Wall,
+                    ..
+                } => ('█', style::Color::Grey),
+                level::Tile { player: true, .. } => ('@', style::Color::Green),
+                level::Tile {
+                    the_box: Some(color),
+                    box_spot,
+                    ..
+                } => (if box_spot.is_some() { '▣' } else { '■' }, to_color(*color)),
+                level::Tile {
+                    box_spot: Some(color),
+                    ..
+                } => ('○', to_color(*color)),
+                level::Tile {
+                    special: Some(special),
+                    ..
+                } => (
+                    special.xsb_symbol().unwrap_or('?'),
+                    match special {
+                        level::Special::Ice => style::Color::Cyan,
+                        level::Special::Teleporter(group)
+                        | level::Special::Switch(group)
+                        | level::Special::Door(group) => {
+                            to_color(components::BoxColor::Color(*group))
+                        }
+                        level::Special::Key => style::Color::Yellow,
+                        _ => style::Color::Grey,
+                    },
+                ),
+                _ => ('·', style::Color::DarkGrey),
+            };
+            queue!(
+                stdout,
+                style::SetForegroundColor(color),
+                style::Print(symbol),
+                style::Print(' ')
+            )?;
+        }
+        queue!(
+            stdout,
+            style::ResetColor,
+            terminal::Clear(terminal::ClearType::UntilNewLine)
+        )?;
+    }
+
+    if let Some(gameplay) = resources.get::<resources::GamePlay>() {
+        let lines = [
+            String::new(),
+            gameplay.state.to_string(),
+            format!("Moves: {}", gameplay.steps_taken),
+            "Arrows/WASD: move, Tab: switch player, Z: undo, Y: redo, R: restart, Esc: quit"
+                .to_string(),
+        ];
+        for (offset, line) in lines.iter().enumerate() {
+            queue!(
+                stdout,
+                cursor::MoveTo(0, level.height() as u16 + offset as u16),
+                style::Print(line),
+                terminal::Clear(terminal::ClearType::UntilNewLine)
+            )?;
+        }
+    }
+
+    stdout.flush()?;
+    Ok(())
+}
+
+/// Get the terminal color of boxes and box spots, using the same tint as the game's images.
+fn to_color(color: components::BoxColor) -> style::Color {
+    let (r, g, b) = color.tint().to_rgb();
+    style::Color::Rgb { r, g, b }
+}
@@ -1,5 +1,8 @@
+use ggez::graphics;
 use ggez::mint;
 
+use crate::resources;
+
 /// This component determines if a renderable entity is rendered with a single resource (static)
 /// it is rendered with multiple sources (animated).
 pub enum RenderableKind {
@@ -7,18 +10,34 @@ pub enum RenderableKind {
     Animated,
 }
 
-/// A renderable entity can be drawn on to the game screen.
+/// A renderable entity can be drawn on to the game screen. The colors of its images are
+/// multiplied by its tint when drawn.
 pub struct Renderable {
     paths: Vec<String>,
+    tint: graphics::Color,
 }
 
 impl Renderable {
     pub fn new_static(path: String) -> Self {
-        Self { paths: vec![path] }
+        Self {
+            paths: vec![path],
+            tint: graphics::WHITE,
+        }
     }
 
     pub fn new_animated(paths: Vec<String>) -> Self {
-        Self { paths }
+        Self {
+            paths,
+            tint: graphics::WHITE,
+        }
+    }
+
+    pub fn with_tint(self, tint: graphics::Color) -> Self {
+        Self { tint, ..self }
+    }
+
+    pub fn tint(&self) -> graphics::Color {
+        self.tint
     }
 
     pub fn kind(&self) -> RenderableKind {
@@ -38,11 +57,73 @@ impl Renderable {
 /// is drawn onto or below another renderable entity.
 pub type Position = mint::Point3<u8>;
 
-/// This component determines the color of a box archetype.
-#[derive(PartialEq)]
+/// The named colors of boxes and box spots, along with the letter that stands for them in the map
+/// tokens and the tint of their images. Colors past the palette are referred to by their number.
+pub const PALETTE: [(&str, char, (u8, u8, u8)); 7] = [
+    ("blue", 'B', (3, 110, 159)),
+    ("red", 'R', (159, 3, 39)),
+    ("green", 'G', (24, 130, 48)),
+    ("yellow", 'Y', (214, 168, 18)),
+    ("orange", 'O', (222, 108, 20)),
+    ("cyan", 'C', (20, 168, 168)),
+    ("magenta", 'M', (170, 40, 150)),
+];
+
+/// The tint of neutral boxes and wildcard box spots.
+const NEUTRAL_TINT: (u8, u8, u8) = (120, 120, 120);
+
+/// This component determines the color of a box archetype. A box is in its correct position on a
+/// box spot of the same color, while neutral boxes and wildcard box spots go with any color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum BoxColor {
-    Blue,
-    Red,
+    Any,
+    Color(u8),
+}
+
+impl BoxColor {
+    pub const BLUE: Self = BoxColor::Color(0);
+    pub const RED: Self = BoxColor::Color(1);
+
+    /// Check if a box of this color can be put on a box spot of the other color, or the other
+    /// way around.
+    pub fn matches(self, other: Self) -> bool {
+        self == BoxColor::Any || other == BoxColor::Any || self == other
+    }
+
+    /// Get the tint of the images of boxes and box spots of this color. Colors past the palette
+    /// get hues that are spread around the color wheel.
+    pub fn tint(self) -> graphics::Color {
+        let (r, g, b) = match self {
+            BoxColor::Any => NEUTRAL_TINT,
+            BoxColor::Color(idx) => match PALETTE.get(idx as usize) {
+                Some((_, _, rgb)) => *rgb,
+                None => hue_to_rgb((idx as f32 * 0.618_034).fract()),
+            },
+        };
+        graphics::Color::from_rgb(r, g, b)
+    }
+}
+
+/// Convert a hue in `[0, 1)` to a color with a fixed saturation and value.
+fn hue_to_rgb(hue: f32) -> (u8, u8, u8) {
+    let (saturation, value) = (0.85, 0.7);
+    let sector = hue * 6.0;
+    let x = value * saturation * (1.0 - (sector % 2.0 - 1.0).abs());
+    let c = value * saturation;
+    let (r, g, b) = match sector as u8 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let m = value - c;
+    (
+        ((r + m) * 255.0) as u8,
+        ((g + m) * 255.0) as u8,
+        ((b + m) * 255.0) as u8,
+    )
 }
 
 /// Marker represents a box in sokoban.
@@ -55,14 +136,20 @@ pub struct BoxSpot {
     pub color: BoxColor,
 }
 
-/// Marker represents a player in sokoban.
-#[derive(Default)]
-pub struct Player;
+/// Marker represents a player in sokoban. A level can have several player characters, which are
+/// numbered in reading order of their initial positions.
+pub struct Player {
+    pub index: usize,
+}
 
 /// Marker represents a wall in sokoban.
 #[derive(Default)]
 pub struct Wall;
 
+/// Marker represents the plain floor under the other objects of a level.
+#[derive(Default)]
+pub struct Floor;
+
 /// A moveable entity can be moved by the player.
 #[derive(Default)]
 pub struct Movable;
@@ -70,3 +157,55 @@ pub struct Movable;
 /// A moveable entity can not be moved by the player.
 #[derive(Default)]
 pub struct Immovable;
+
+/// Marker represents an icy floor. Entities that move onto ice keep sliding in the same direction
+/// until they are blocked or leave the ice.
+#[derive(Default)]
+pub struct Ice;
+
+/// A floor that entities can only move onto, or off, in its direction.
+pub struct OneWay {
+    pub direction: resources::Direction,
+}
+
+/// A hole in the floor. The players can not walk into a hole, but a box that is pushed into it
+/// fills it, after which the hole is walked over like the floor.
+#[derive(Default)]
+pub struct Hole {
+    /// The box that fills the hole.
+    pub filled_by: Option<legion::Entity>,
+}
+
+/// A teleporter, which takes the entities that move onto it to the other teleporter of the same
+/// pair, unless the other teleporter is occupied.
+pub struct Teleporter {
+    pub pair: u8,
+}
+
+/// A switch in the floor. The doors of its group are open while a player or a box stands on any
+/// switch of the group.
+pub struct Switch {
+    pub group: u8,
+}
+
+/// A door that blocks like a wall unless it is open. A door is open while a switch of its group
+/// is pressed, and can not close while something stands in it.
+pub struct Door {
+    pub group: u8,
+    pub is_open: bool,
+}
+
+/// A door that blocks like a wall until a player walks into it with a key, which opens it for
+/// good.
+#[derive(Default)]
+pub struct LockedDoor {
+    /// The key that opened the door.
+    pub opened_with: Option<legion::Entity>,
+}
+
+/// A key, which is picked up by the player that walks onto it. A key opens a single locked door.
+#[derive(Default)]
+pub struct Key {
+    /// The player that picked up the key.
+    pub held_by: Option<legion::Entity>,
+}
@@ -0,0 +1,121 @@
+use crate::game;
+use crate::resources;
+
+/// The number of ticks between the moves of a demonstration at each speed, from the slowest to
+/// the fastest.
+const MOVE_DELAYS: &[u32] = &[60, 30, 15, 8, 4, 2, 1];
+
+/// The speed that a demonstration starts at, as an index in `MOVE_DELAYS`.
+const DEFAULT_SPEED: usize = 2;
+
+/// A solution that is animated on the board move by move. The moves are made by the first player
+/// and go through the action queue like the moves of the players, so the board and the move
+/// counter always show where the demonstration is. Stepping back undoes the last move.
+#[derive(Debug)]
+pub struct Demonstration {
+    moves: Vec<resources::Direction>,
+    /// The number of moves of the solution that were made.
+    played: usize,
+    pub is_paused: bool,
+    speed: usize,
+    /// The number of ticks before the next move is made.
+    wait: u32,
+}
+
+impl Demonstration {
+    /// Create a demonstration of a solution in the LURD format, if the solution is valid.
+    pub fn from_lurd(solution: &str) -> Option<Self> {
+        let moves = solution
+            .chars()
+            .filter(|symbol| !symbol.is_whitespace())
+            .map(resources::Direction::from_lurd)
+            .collect::<Option<Vec<_>>>()?;
+        Some(Self {
+            moves,
+            played: 0,
+            is_paused: false,
+            speed: DEFAULT_SPEED,
+            wait: MOVE_DELAYS[DEFAULT_SPEED],
+        })
+    }
+
+    /// Advance the demonstration by a tick, and get the next move once it is due.
+    pub fn update(&mut self) -> Option<resources::Action> {
+        if self.is_paused || self.is_finished() {
+            return None;
+        }
+        if self.wait > 1 {
+            self.wait -= 1;
+            return None;
+        }
+        self.wait = MOVE_DELAYS[self.speed];
+        self.step_forward()
+    }
+
+    /// Handle the actions that control the demonstration, and get the move that they make, if
+    /// any. Stepping pauses the demonstration.
+    pub fn control(&mut self, action: resources::Action) -> Option<resources::Action> {
+        match action {
+            resources::Action::PausePlayback => {
+                self.is_paused = !self.is_paused;
+                self.wait = MOVE_DELAYS[self.speed];
+                None
+            }
+            resources::Action::StepPlayback => {
+                self.is_paused = true;
+                self.step_forward()
+            }
+            resources::Action::StepBackPlayback => {
+                self.is_paused = true;
+                self.step_back()
+            }
+            resources::Action::SpeedUpPlayback => {
+                self.speed = (self.speed + 1).min(MOVE_DELAYS.len() - 1);
+                self.wait = self.wait.min(MOVE_DELAYS[self.speed]);
+                None
+            }
+            resources::Action::SlowDownPlayback => {
+                self.speed = self.speed.saturating_sub(1);
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Check if every move of the solution was made.
+    pub fn is_finished(&self) -> bool {
+        self.played >= self.moves.len()
+    }
+
+    /// Describe the progress of the demonstration and its controls.
+    pub fn status(&self) -> String {
+        let state = if self.is_finished() {
+            "Finished"
+        } else if self.is_paused {
+            "Paused"
+        } else {
+            "Playing"
+        };
+        format!(
+            "Solution: {}/{}\n{} ({:.1} moves/s)\n\nSpace: pause\n.: step\n,: step back\n+/-: speed",
+            self.played,
+            self.moves.len(),
+            state,
+            game::FPS as f32 / MOVE_DELAYS[self.speed] as f32,
+        )
+    }
+
+    fn step_forward(&mut self) -> Option<resources::Action> {
+        let direction = *self.moves.get(self.played)?;
+        self.played += 1;
+        Some(resources::Action::MovePlayer(0, direction))
+    }
+
+    fn step_back(&mut self) -> Option<resources::Action> {
+        if self.played == 0 {
+            return None;
+        }
+        self.played -= 1;
+        Some(resources::Action::Undo)
+    }
+}
@@ -0,0 +1,162 @@
+use std::fmt;
+
+use crate::level;
+use crate::resources;
+use crate::solver;
+use crate::state;
+
+/// The number of positions that the solver may expand when estimating the difficulty of a level.
+pub const NODE_LIMIT: usize = 200_000;
+
+/// The measures that make up the difficulty of a level.
+#[derive(Debug, Clone)]
+pub struct Difficulty {
+    /// The number of positions that the solver expanded to find the solution.
+    pub nodes_expanded: usize,
+    /// The fewest pushes that solve the level, if the solver found a solution.
+    pub pushes: Option<usize>,
+    /// The number of pushes in the solution that move a box onto or off a box spot.
+    pub goal_interactions: usize,
+    /// The fraction of the floor from which a box can never reach a box spot.
+    pub deadlock_density: f64,
+    /// The combined difficulty score, where higher is harder.
+    pub score: f64,
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.pushes {
+            Some(pushes) => write!(f, "{:.1} ({} pushes)", self.score, pushes),
+            None => write!(f, "unknown (unsolved)"),
+        }
+    }
+}
+
+/// Estimate the difficulty of a level. The score grows with the number of pushes in the optimal
+/// solution, with the logarithm of the effort of the solver, with the number of times boxes are
+/// moved onto or off box spots, and with the fraction of the floor where boxes are deadlocked.
+/// The difficulty of levels with special floors is not estimated, since the solver does not
+/// support them.
+pub fn estimate(level: &level::Level) -> Option<Difficulty> {
+    from_search(level, &solver::solve(level, NODE_LIMIT))
+}
+
+/// Estimate the difficulty of a level from the search of a solver that was given the level and
+/// [`NODE_LIMIT`], so that the search can be run a slice at a time, see [`estimate`].
+pub fn from_search(level: &level::Level, search: &solver::Search) -> Option<Difficulty> {
+    let deadlock_density = deadlock_density(level);
+    let (pushes, goal_interactions) = match &search.outcome {
+        solver::Outcome::Solved(solution) => (
+            Some(solution.pushes),
+            goal_interactions(level, &solution.moves),
+        ),
+        solver::Outcome::Unsolved => (None, 0),
+        solver::Outcome::Unsupported => return None,
+    };
+
+    let score = pushes.unwrap_or(0) as f64
+        + 4.0 * ((search.nodes_expanded + 1) as f64).log2()
+        + 2.0 * goal_interactions as f64
+        + 10.0 * deadlock_density;
+
+    Some(Difficulty {
+        nodes_expanded: search.nodes_expanded,
+        pushes,
+        goal_interactions,
+        deadlock_density,
+        score,
+    })
+}
+
+/// Get the fraction of the floor where boxes can not be pushed onto a box spot of any color.
+fn deadlock_density(level: &level::Level) -> f64 {
+    let mut colors = Vec::new();
+    for (_, tile) in level.tiles() {
+        if let Some(color) = tile.box_spot {
+            if !colors.contains(&color) {
+                colors.push(color);
+            }
+        }
+    }
+    let live = colors
+        .into_iter()
+        .map(|color| level.live_squares(color))
+        .collect::<Vec<_>>();
+
+    let floor = level
+        .tiles()
+        .filter(|(_, tile)| tile.terrain == level::Terrain::Floor)
+        .map(|(position, _)| position)
+        .collect::<Vec<_>>();
+    if floor.is_empty() {
+        return 0.0;
+    }
+    let dead = floor
+        .iter()
+        .filter(|position| live.iter().all(|live| !live.contains(position)))
+        .count();
+    dead as f64 / floor.len() as f64
+}
+
+/// Count the pushes of the solution that move a box onto or off a box spot.
+fn goal_interactions(level: &level::Level, moves: &str) -> usize {
+    let mut state = match state::State::from_level(level) {
+        Some(state) => state,
+        None => return 0,
+    };
+
+    let mut interactions = 0;
+    for symbol in moves.chars() {
+        let direction = match resources::Direction::from_lurd(symbol) {
+            Some(direction) => direction,
+            None => continue,
+        };
+        let next = match state.step(state.player(), direction) {
+            Some(next) => next,
+            None => continue,
+        };
+        let target = state.step(next, direction);
+        let is_push = state.apply(direction) == Some(true);
+        if is_push
+            && (state.box_spot_at(next).is_some()
+                || target.is_some_and(|target| state.box_spot_at(target).is_some()))
+        {
+            interactions += 1;
+        }
+    }
+    interactions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn harder_levels_score_higher() {
+        let trivial = estimate(&level::Level::from_test_xsb("#####\n#@$.#\n#####\n"))
+            .expect("The level has no special floors");
+        let harder = estimate(&level::Level::from_test_xsb(
+            "########\n#@     #\n# $ $  #\n#  ##  #\n# .  . #\n########\n",
+        ))
+        .expect("The level has no special floors");
+        assert_eq!(trivial.pushes, Some(1));
+        assert!(harder.pushes.is_some_and(|pushes| pushes > 1));
+        assert!(harder.nodes_expanded > trivial.nodes_expanded);
+        assert!(harder.score > trivial.score);
+    }
+
+    #[test]
+    fn corners_are_deadlocks() {
+        // Only the corner that the player starts in is dead.
+        let level = level::Level::from_test_xsb("#####\n#@$.#\n#####\n");
+        assert!((deadlock_density(&level) - 1.0 / 3.0).abs() < 1e-9);
+        let open = level::Level::from_test_xsb("#####\n#@  #\n#  .#\n#####\n");
+        assert!(deadlock_density(&open) > 0.0);
+        assert!(deadlock_density(&open) < 1.0);
+    }
+
+    #[test]
+    fn special_floors_are_not_estimated() {
+        assert!(estimate(&level::Level::from_test_xsb("######\n#@$~.#\n######\n")).is_none());
+    }
+}
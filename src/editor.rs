@@ -0,0 +1,241 @@
+use ggez::filesystem;
+use ggez::graphics;
+use ggez::input::keyboard;
+use ggez::mint;
+
+use std::io::Write;
+
+use crate::components;
+use crate::entities;
+use crate::game::{MAP_HEIGHT, MAP_WIDTH, TILE_HEIGHT, TILE_WIDTH};
+use crate::level;
+use crate::systems;
+
+/// The smallest size of a level that can be created in the editor.
+const MIN_SIZE: u8 = 3;
+
+/// The path, within the game's user data directory, where edited levels are saved.
+pub const SAVE_PATH: &str = "/levels/custom.xsb";
+
+/// The content that is painted onto a tile of the level being edited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Brush {
+    Wall,
+    Floor,
+    Player,
+    Box(components::BoxColor),
+    BoxSpot(components::BoxColor),
+    Erase,
+}
+
+impl Brush {
+    /// Get the brush that is selected by the key, if any.
+    pub fn from_keycode(keycode: keyboard::KeyCode) -> Option<Self> {
+        match keycode {
+            keyboard::KeyCode::Key1 => Some(Brush::Wall),
+            keyboard::KeyCode::Key2 => Some(Brush::Floor),
+            keyboard::KeyCode::Key3 => Some(Brush::Player),
+            keyboard::KeyCode::Key4 => Some(Brush::Box(components::BoxColor::BLUE)),
+            keyboard::KeyCode::Key5 => Some(Brush::Box(components::BoxColor::RED)),
+            keyboard::KeyCode::Key6 => Some(Brush::BoxSpot(components::BoxColor::BLUE)),
+            keyboard::KeyCode::Key7 => Some(Brush::BoxSpot(components::BoxColor::RED)),
+            keyboard::KeyCode::Key8 => Some(Brush::Box(components::BoxColor::Any)),
+            keyboard::KeyCode::Key9 => Some(Brush::BoxSpot(components::BoxColor::Any)),
+            keyboard::KeyCode::Key0 => Some(Brush::Erase),
+            _ => None,
+        }
+    }
+
+    /// Get the brush with the next color of the palette, followed by any color, if the brush
+    /// paints boxes or box spots.
+    pub fn with_next_color(self) -> Self {
+        let next_color = |color| match color {
+            components::BoxColor::Any => components::BoxColor::Color(0),
+            components::BoxColor::Color(idx) if (idx as usize) + 1 < components::PALETTE.len() => {
+                components::BoxColor::Color(idx + 1)
+            }
+            components::BoxColor::Color(_) => components::BoxColor::Any,
+        };
+        match self {
+            Brush::Box(color) => Brush::Box(next_color(color)),
+            Brush::BoxSpot(color) => Brush::BoxSpot(next_color(color)),
+            brush => brush,
+        }
+    }
+
+    /// Change the tile according to the brush.
+    pub fn paint(self, tile: &mut level::Tile) {
+        match self {
+            Brush::Wall => {
+                *tile = level::Tile {
+                    terrain: level::Terrain::Wall,
+                    ..level::Tile::default()
+                }
+            }
+            Brush::Floor => {
+                *tile = level::Tile {
+                    terrain: level::Terrain::Floor,
+                    ..level::Tile::default()
+                }
+            }
+            Brush::Player => {
+                tile.terrain = level::Terrain::Floor;
+                tile.player = true;
+                tile.the_box = None;
+            }
+            Brush::Box(color) => {
+                tile.terrain = level::Terrain::Floor;
+                tile.player = false;
+                tile.the_box = Some(color);
+            }
+            Brush::BoxSpot(color) => {
+                tile.terrain = level::Terrain::Floor;
+                tile.box_spot = Some(color);
+            }
+            Brush::Erase => *tile = level::Tile::default(),
+        }
+    }
+}
+
+impl std::fmt::Display for Brush {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Brush::Wall => f.write_str("Wall"),
+            Brush::Floor => f.write_str("Floor"),
+            Brush::Player => f.write_str("Player"),
+            Brush::Box(color) => write!(f, "{} box", level::color_name(*color)),
+            Brush::BoxSpot(color) => write!(f, "{} box spot", level::color_name(*color)),
+            Brush::Erase => f.write_str("Erase"),
+        }
+    }
+}
+
+/// The editor scene, where levels are created by painting tiles with brushes. The level being
+/// edited is kept as a grid of tiles, and the world that displays it is rebuilt from the grid
+/// whenever the level changes.
+pub struct Editor {
+    level: level::Level,
+    world: legion::World,
+    pub brush: Brush,
+    pub message: String,
+}
+
+impl Editor {
+    pub fn new(level: level::Level) -> Self {
+        let level = level.resized(
+            level.width().clamp(MIN_SIZE, MAP_WIDTH),
+            level.height().clamp(MIN_SIZE, MAP_HEIGHT),
+        );
+        let mut editor = Self {
+            level,
+            world: legion::World::default(),
+            brush: Brush::Wall,
+            message: String::new(),
+        };
+        editor.rebuild_world();
+        editor
+    }
+
+    pub fn level(&self) -> &level::Level {
+        &self.level
+    }
+
+    /// Paint the tile at the given position on the screen with the selected brush.
+    pub fn paint_at(&mut self, screen_x: f32, screen_y: f32) {
+        self.apply_at(screen_x, screen_y, self.brush);
+    }
+
+    /// Clear the tile at the given position on the screen.
+    pub fn erase_at(&mut self, screen_x: f32, screen_y: f32) {
+        self.apply_at(screen_x, screen_y, Brush::Erase);
+    }
+
+    /// Change the size of the level by the given number of tiles, within the size of the screen.
+    pub fn resize_by(&mut self, dw: i8, dh: i8) {
+        let width =
+            (self.level.width() as i16 + dw as i16).clamp(MIN_SIZE as i16, MAP_WIDTH as i16);
+        let height =
+            (self.level.height() as i16 + dh as i16).clamp(MIN_SIZE as i16, MAP_HEIGHT as i16);
+        self.level = self.level.resized(width as u8, height as u8);
+        self.rebuild_world();
+    }
+
+    /// Check if the level can be played, and report the problems of the level otherwise.
+    pub fn validate(&mut self) -> bool {
+        let problems = self.level.validate();
+        self.message = problems
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n");
+        problems.is_empty()
+    }
+
+    /// Save the level in the XSB format to the game's user data directory.
+    pub fn save(&mut self, ctx: &mut ggez::Context) -> ggez::GameResult {
+        let mut file = filesystem::create(ctx, SAVE_PATH)?;
+        file.write_all(self.level.to_colored_xsb().as_bytes())?;
+        self.message = format!("Saved to {}", SAVE_PATH);
+        Ok(())
+    }
+
+    /// Draw the level being edited along with the editor's state.
+    pub fn draw(&self, ctx: &mut ggez::Context, resources: &legion::Resources) -> ggez::GameResult {
+        systems::render_entities(ctx, &self.world, resources, systems::Viewport::default())?;
+
+        let bounds = graphics::Mesh::new_rectangle(
+            ctx,
+            graphics::DrawMode::stroke(2.0),
+            graphics::Rect::new(
+                0.0,
+                0.0,
+                self.level.width() as f32 * TILE_WIDTH,
+                self.level.height() as f32 * TILE_HEIGHT,
+            ),
+            graphics::Color::new(0.0, 0.0, 0.0, 1.0),
+        )?;
+        graphics::draw(ctx, &bounds, graphics::DrawParam::new())?;
+
+        let text = graphics::Text::new(
+            graphics::TextFragment::new(format!(
+                "Editor\nBrush: {}\nSize: {}x{}\n\n\
+                 0-9: brushes\nC: next color\nArrows: resize\nS: save\nEnter: play\nEsc: leave\n\n{}",
+                self.brush,
+                self.level.width(),
+                self.level.height(),
+                self.message,
+            ))
+            .color(graphics::Color::new(0.0, 0.0, 0.0, 1.0)),
+        );
+        let draw_dest = mint::Point2 {
+            x: TILE_WIDTH * MAP_WIDTH as f32 + 50.0,
+            y: 20.0,
+        };
+        graphics::draw(ctx, &text, graphics::DrawParam::new().dest(draw_dest))
+    }
+
+    fn apply_at(&mut self, screen_x: f32, screen_y: f32, brush: Brush) {
+        if screen_x < 0.0 || screen_y < 0.0 {
+            return;
+        }
+        let x = (screen_x / TILE_WIDTH) as u8;
+        let y = (screen_y / TILE_HEIGHT) as u8;
+        if x >= self.level.width() || y >= self.level.height() {
+            return;
+        }
+
+        let mut tile = *self.level.get(x, y);
+        brush.paint(&mut tile);
+        if tile != *self.level.get(x, y) {
+            *self.level.get_mut(x, y) = tile;
+            self.rebuild_world();
+        }
+    }
+
+    /// Replace the world with the entities of the level. A new world is created rather than
+    /// cleared, so that the cached sprite batches of the old one are not drawn.
+    fn rebuild_world(&mut self) {
+        self.world = legion::World::default();
+        entities::create_entities_from_level(&mut self.world, &self.level);
+    }
+}
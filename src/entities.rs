@@ -1,10 +1,13 @@
 use crate::components;
 use crate::game::{MAP_HEIGHT, MAP_WIDTH};
+use crate::level;
+use crate::resources;
 
 pub fn create_entities_from_map(
     world: &mut legion::World,
     map: Vec<(components::Position, &str)>,
 ) -> ggez::GameResult {
+    let mut players = 0;
     for (position, val) in map {
         if position.x >= MAP_WIDTH || position.y > MAP_HEIGHT {
             return Err(ggez::GameError::ResourceLoadError(
@@ -12,30 +15,78 @@ pub fn create_entities_from_map(
             ));
         }
         match val {
-            // blue box
-            "BB" => {
+            // box of the color given by the token's prefix, e.g. `BB` for blue or `AB` for neutral
+            c if c.len() > 1 && c.ends_with('B') => {
                 create_floor(world, position);
-                create_box(world, position, components::BoxColor::Blue);
+                create_box(world, position, color_from_token(&c[..c.len() - 1])?);
             }
-            // red box
-            "RB" => {
+            // box destination of the color given by the token's prefix, e.g. `RS` for red or
+            // `AS` for wildcard
+            c if c.len() > 1 && c.ends_with('S') => {
                 create_floor(world, position);
-                create_box(world, position, components::BoxColor::Red);
+                create_box_spot(world, position, color_from_token(&c[..c.len() - 1])?);
             }
-            // blue box destination
-            "BS" => {
+            // player initial position
+            "P" => {
                 create_floor(world, position);
-                create_box_spot(world, position, components::BoxColor::Blue);
+                create_player(world, position, players);
+                players += 1;
             }
-            // red box destination
-            "RS" => {
+            // ice
+            "I" => {
                 create_floor(world, position);
-                create_box_spot(world, position, components::BoxColor::Red);
+                create_special(world, position, level::Special::Ice);
             }
-            // player initial position
-            "P" => {
+            // one-way floor in the direction of the arrow
+            "^" | "v" | "<" | ">" => {
+                create_floor(world, position);
+                let direction = match val {
+                    "^" => resources::Direction::Up,
+                    "v" => resources::Direction::Down,
+                    "<" => resources::Direction::Left,
+                    _ => resources::Direction::Right,
+                };
+                create_special(world, position, level::Special::OneWay(direction));
+            }
+            // hole
+            "H" => {
+                create_floor(world, position);
+                create_special(world, position, level::Special::Hole);
+            }
+            // teleporter of the pair given by the token's number, e.g. `T1`
+            c if c.len() > 1 && c.starts_with('T') => {
+                let pair = c[1..].parse().map_err(|_| {
+                    ggez::GameError::ResourceLoadError(format!("Invalid teleporter {}", c))
+                })?;
+                create_floor(world, position);
+                create_special(world, position, level::Special::Teleporter(pair));
+            }
+            // switch and door of the group given by the token's number, e.g. `X1` and `D1`, from
+            // 0 to 8
+            c if c.len() > 1 && (c.starts_with('X') || c.starts_with('D')) => {
+                let group = c[1..]
+                    .parse()
+                    .ok()
+                    .filter(|group| *group < level::Special::GROUPS)
+                    .ok_or_else(|| {
+                        ggez::GameError::ResourceLoadError(format!("Invalid switch or door {}", c))
+                    })?;
                 create_floor(world, position);
-                create_player(world, position);
+                if c.starts_with('X') {
+                    create_special(world, position, level::Special::Switch(group));
+                } else {
+                    create_special(world, position, level::Special::Door(group));
+                }
+            }
+            // locked door
+            "L" => {
+                create_floor(world, position);
+                create_special(world, position, level::Special::LockedDoor);
+            }
+            // key
+            "K" => {
+                create_floor(world, position);
+                create_special(world, position, level::Special::Key);
             }
             // wall
             "W" => {
@@ -55,9 +106,57 @@ pub fn create_entities_from_map(
     Ok(())
 }
 
-pub fn create_player(world: &mut legion::World, pos: components::Position) -> legion::Entity {
+/// Get the color that is given by the prefix of a map token, which is either `A` for any color,
+/// the letter of a color in the palette, or the number of a color.
+fn color_from_token(prefix: &str) -> ggez::GameResult<components::BoxColor> {
+    if prefix == "A" {
+        return Ok(components::BoxColor::Any);
+    }
+    components::PALETTE
+        .iter()
+        .position(|(_, letter, _)| prefix.len() == 1 && prefix.starts_with(*letter))
+        .map(|idx| idx as u8)
+        .or_else(|| prefix.parse().ok())
+        .map(components::BoxColor::Color)
+        .ok_or_else(|| ggez::GameError::ResourceLoadError(format!("Invalid box color {}", prefix)))
+}
+
+pub fn create_entities_from_level(world: &mut legion::World, level: &level::Level) {
+    let mut players = 0;
+    for ((x, y), tile) in level.tiles() {
+        let position = components::Position { x, y, z: 0 };
+        match tile.terrain {
+            level::Terrain::Wall => {
+                create_wall(world, position);
+            }
+            level::Terrain::Floor => {
+                create_floor(world, position);
+                if let Some(special) = tile.special {
+                    create_special(world, position, special);
+                }
+                if let Some(color) = tile.box_spot {
+                    create_box_spot(world, position, color);
+                }
+                if let Some(color) = tile.the_box {
+                    create_box(world, position, color);
+                }
+                if tile.player {
+                    create_player(world, position, players);
+                    players += 1;
+                }
+            }
+            level::Terrain::Void => {}
+        }
+    }
+}
+
+pub fn create_player(
+    world: &mut legion::World,
+    pos: components::Position,
+    index: usize,
+) -> legion::Entity {
     world.push((
-        components::Player,
+        components::Player { index },
         components::Movable,
         components::Position { z: 10, ..pos },
         components::Renderable::new_animated(vec![
@@ -73,22 +172,15 @@ pub fn create_box(
     pos: components::Position,
     color: components::BoxColor,
 ) -> legion::Entity {
-    let paths = match color {
-        components::BoxColor::Red => vec![
-            "/images/box_red_1.png".to_string(),
-            "/images/box_red_2.png".to_string(),
-        ],
-        components::BoxColor::Blue => vec![
-            "/images/box_blue_1.png".to_string(),
-            "/images/box_blue_2.png".to_string(),
-        ],
-    };
-
     world.push((
         components::Box { color },
         components::Movable,
         components::Position { z: 10, ..pos },
-        components::Renderable::new_animated(paths),
+        components::Renderable::new_animated(vec![
+            "/images/box_1.png".to_string(),
+            "/images/box_2.png".to_string(),
+        ])
+        .with_tint(color.tint()),
     ))
 }
 
@@ -106,21 +198,91 @@ pub fn create_box_spot(
     pos: components::Position,
     color: components::BoxColor,
 ) -> legion::Entity {
-    let path = match color {
-        components::BoxColor::Red => "/images/box_spot_red.png".to_string(),
-        components::BoxColor::Blue => "/images/box_spot_blue.png".to_string(),
-    };
-
     world.push((
         components::BoxSpot { color },
         components::Position { z: 9, ..pos },
-        components::Renderable::new_static(path),
+        components::Renderable::new_static("/images/box_spot.png".to_string())
+            .with_tint(color.tint()),
     ))
 }
 
 pub fn create_floor(world: &mut legion::World, floor_pos: components::Position) -> legion::Entity {
     world.push((
+        components::Floor,
         components::Position { z: 5, ..floor_pos },
         components::Renderable::new_static("/images/floor.png".to_string()),
     ))
 }
+
+/// Create the entity of a special floor, which is drawn over the plain floor. Doors are drawn
+/// like walls, and keys like objects on the floor.
+pub fn create_special(
+    world: &mut legion::World,
+    pos: components::Position,
+    special: level::Special,
+) -> legion::Entity {
+    let pos = components::Position { z: 6, ..pos };
+    match special {
+        level::Special::Ice => world.push((
+            components::Ice,
+            pos,
+            components::Renderable::new_static("/images/ice.png".to_string()),
+        )),
+        level::Special::OneWay(direction) => {
+            // Diagonal one-way floors are drawn like the vertical one that is closest to them.
+            let path = match direction {
+                resources::Direction::Up
+                | resources::Direction::UpLeft
+                | resources::Direction::UpRight => "/images/one_way_up.png",
+                resources::Direction::Down
+                | resources::Direction::DownLeft
+                | resources::Direction::DownRight => "/images/one_way_down.png",
+                resources::Direction::Left => "/images/one_way_left.png",
+                resources::Direction::Right => "/images/one_way_right.png",
+            };
+            world.push((
+                components::OneWay { direction },
+                pos,
+                components::Renderable::new_static(path.to_string()),
+            ))
+        }
+        level::Special::Hole => world.push((
+            components::Hole::default(),
+            pos,
+            components::Renderable::new_static("/images/hole.png".to_string()),
+        )),
+        // The pairs are told apart by the tint of their teleporters.
+        level::Special::Teleporter(pair) => world.push((
+            components::Teleporter { pair },
+            pos,
+            components::Renderable::new_static("/images/teleporter.png".to_string())
+                .with_tint(components::BoxColor::Color(pair).tint()),
+        )),
+        // The switches and the doors of a group have the same tint.
+        level::Special::Switch(group) => world.push((
+            components::Switch { group },
+            pos,
+            components::Renderable::new_static("/images/switch.png".to_string())
+                .with_tint(components::BoxColor::Color(group).tint()),
+        )),
+        level::Special::Door(group) => world.push((
+            components::Door {
+                group,
+                is_open: false,
+            },
+            components::Position { z: 10, ..pos },
+            components::Renderable::new_static("/images/door.png".to_string())
+                .with_tint(components::BoxColor::Color(group).tint()),
+        )),
+        level::Special::LockedDoor => world.push((
+            components::LockedDoor::default(),
+            components::Position { z: 10, ..pos },
+            components::Renderable::new_static("/images/locked_door.png".to_string()),
+        )),
+        level::Special::Key => world.push((
+            components::Key::default(),
+            components::Position { z: 8, ..pos },
+            components::Renderable::new_static("/images/key.png".to_string()),
+        )),
+    }
+}
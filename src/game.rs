@@ -1,12 +1,39 @@
 use ggez::event;
+use ggez::filesystem;
 use ggez::graphics;
 use ggez::input::keyboard;
+use ggez::input::mouse;
+use ggez::mint;
 use ggez::timer;
+use legion::query::IntoQuery;
 
+use std::io::{Read, Write};
+
+use crate::audio_backend;
 use crate::components;
+use crate::demonstration;
+use crate::editor;
 use crate::entities;
+use crate::level;
+use crate::level_select;
+use crate::race;
+use crate::replay;
 use crate::resources;
+use crate::solver;
 use crate::systems;
+use crate::topology;
+
+// TODO: Load map from persistence
+pub const DEFAULT_MAP: &str = "\
+    N N W W W W W W W
+    W W W . . . . . W
+    W . . . BB . . . W
+    W . . RB . . . . W
+    W . P . . . . . W
+    W . . . . RS . . W
+    W . . BS . . . . W
+    W . . . . . . . W
+    W W W W W W W W W";
 
 pub const MAP_WIDTH: u8 = 9;
 pub const MAP_HEIGHT: u8 = 9;
@@ -17,87 +44,673 @@ pub const TILE_HEIGHT: f32 = 48.0;
 pub const ARENA_WIDTH: f32 = 720.0;
 pub const ARENA_HEIGHT: f32 = MAP_HEIGHT as f32 * TILE_HEIGHT;
 
-const FPS: u32 = 60;
+pub const FPS: u32 = 60;
 
-const SOUNDS: &[&str] = &[
-    "/sounds/wall.wav",
-    "/sounds/correct.wav",
-    "/sounds/incorrect.wav",
-];
+/// How far a gamepad's axis must be pushed before it is considered as held in a direction.
+const GAMEPAD_AXIS_THRESHOLD: f32 = 0.5;
+
+/// The music that is played in the background, in a loop.
+const MUSIC: &str = "/music/background.wav";
 
 const IMAGES: &[&str] = &[
-    "/images/box_blue_1.png",
-    "/images/box_blue_2.png",
-    "/images/box_red_1.png",
-    "/images/box_red_2.png",
-    "/images/box_spot_blue.png",
-    "/images/box_spot_red.png",
+    "/images/box_1.png",
+    "/images/box_2.png",
+    "/images/box_spot.png",
+    "/images/door.png",
     "/images/floor.png",
+    "/images/hole.png",
+    "/images/ice.png",
+    "/images/key.png",
+    "/images/locked_door.png",
+    "/images/one_way_down.png",
+    "/images/one_way_left.png",
+    "/images/one_way_right.png",
+    "/images/one_way_up.png",
     "/images/player_1.png",
     "/images/player_2.png",
     "/images/player_3.png",
+    "/images/switch.png",
+    "/images/teleporter.png",
     "/images/wall.png",
 ];
 
+/// The collection of levels that is played when no other collection is given.
+pub const DEFAULT_LEVELS: &str = "/levels/default.xsb";
+
+/// The path to the file that binds the sounds of the game's events and sets the volumes.
+const AUDIO_CONFIG_PATH: &str = "/audio.txt";
+
+/// The path to the replay file in the user's data directory.
+const REPLAY_PATH: &str = "/replay.txt";
+
+/// How many positions the solver may expand to find a solution to demonstrate, for levels that
+/// are not stored with a solution.
+const SOLUTION_NODE_LIMIT: usize = 1_000_000;
+
+/// How many positions the solver expands at every update of the game while it looks for a
+/// solution to demonstrate, so that the game keeps being drawn during the search.
+const SOLUTION_NODES_PER_UPDATE: usize = 20_000;
+
+/// The scene that is currently shown by the game.
+enum Scene {
+    /// A level is being chosen from the collection.
+    Selecting,
+    /// The level is being played.
+    Playing,
+    /// Two players race to solve the level.
+    Racing(race::Race),
+    /// A level is being edited.
+    Editing(editor::Editor),
+    /// The level being edited is being played, leaving returns to the editor.
+    TestPlaying(editor::Editor),
+    /// A recorded session is being played back.
+    Replaying(replay::Playback),
+    /// A solution of the level is being animated, leaving returns to playing the level.
+    Demonstrating(demonstration::Demonstration),
+}
+
 pub struct Game {
     world: legion::World,
     resources: legion::Resources,
     schedule: legion::Schedule,
+    level: level::Level,
+    level_select: level_select::LevelSelect,
+    scene: Scene,
+    /// The search for a solution to demonstrate, which is dropped when another level is loaded.
+    solving: Option<solver::Solver>,
 }
 
 impl Game {
-    pub fn new(ctx: &mut ggez::Context, map_str: &str) -> ggez::GameResult<Self> {
-        // Load game's images into memory.
-        let mut audio_store = resources::AudioStore::default();
-        load_sounds(ctx, &mut audio_store, SOUNDS)?;
-
-        // Load game's sound effects into memory.
-        let mut drawable_store = resources::DrawableStore::default();
-        load_images(ctx, &mut drawable_store, IMAGES)?;
-
-        // Load game's map and create the entity as specified by the map.
+    pub fn new(
+        ctx: &mut ggez::Context,
+        levels: Vec<level::Level>,
+        audio_output: audio_backend::Output,
+    ) -> ggez::GameResult<Self> {
+        let level = levels.first().cloned().ok_or_else(|| {
+            ggez::GameError::ResourceLoadError("There is no level to play!".to_string())
+        })?;
         let mut world = legion::World::default();
-        let map = parse_map(map_str);
-        entities::create_entities_from_map(&mut world, map)?;
-
-        // Initialize shared resources.
-        let mut resources = legion::Resources::default();
-        resources.insert(resources::Time::default());
-        resources.insert(resources::GamePlay::default());
-        resources.insert(resources::KeyPressedEventQueue::default());
-        resources.insert(resources::GamePlayEventQueue::default());
-        resources.insert(audio_store);
-        resources.insert(drawable_store);
-
-        let schedule = legion::Schedule::builder()
-            .add_system(systems::input_handling_system())
-            .add_system(systems::game_objective_system())
-            .add_system(systems::consume_gameplay_events_system())
-            .build();
+        entities::create_entities_from_level(&mut world, &level);
+        let mut resources = create_resources();
+        load_stores(ctx, &mut resources, audio_output)?;
+        load_music(ctx, &mut resources)?;
+        let schedule = create_schedule();
 
         Ok(Self {
             world,
             resources,
             schedule,
+            level,
+            level_select: level_select::LevelSelect::new(levels),
+            scene: Scene::Selecting,
+            solving: None,
         })
     }
+
+    /// Replace the world with the entities of the given level and reset the state of the game.
+    /// Under the reverse rules, the level starts from its solved position.
+    fn load_level(&mut self, level: &level::Level) {
+        self.solving = None;
+        self.world.clear();
+        self.resources
+            .insert(resources::Grid::new(level.topology()));
+        if let Some(mut render_cache) = self.resources.get_mut::<systems::RenderCache>() {
+            render_cache.invalidate();
+        }
+
+        match self.resources.get_mut::<resources::Rules>().as_deref_mut() {
+            Some(resources::Rules::Reverse { starts }) => {
+                *starts = level
+                    .tiles()
+                    .filter(|(_, tile)| tile.player)
+                    .map(|(position, _)| position)
+                    .collect();
+                entities::create_entities_from_level(&mut self.world, &level.reversed());
+            }
+            _ => entities::create_entities_from_level(&mut self.world, level),
+        }
+        self.resources.insert(resources::GamePlay {
+            challenge: level.challenge(),
+            ..resources::GamePlay::default()
+        });
+        self.resources.insert(resources::HeldInputs::default());
+        self.resources.insert(resources::ActionQueue::default());
+        self.resources.insert(resources::MoveHistory::default());
+        self.resources
+            .insert(resources::GamePlayEventQueue::default());
+        if let Some(mut control) = self.resources.get_mut::<resources::PlayerControl>() {
+            control.active = 0;
+        }
+
+        let log = match (
+            self.resources.get::<resources::Time>(),
+            self.resources.get::<resources::AnimationMode>(),
+            self.resources.get::<resources::PlayerControl>(),
+        ) {
+            (Some(time), Some(animation_mode), Some(control)) => {
+                resources::ActionLog::new(time.ticks, *animation_mode, control.mode)
+            }
+            _ => resources::ActionLog::default(),
+        };
+        self.resources.insert(log);
+    }
+
+    /// Play back the recorded session, starting from the level with the modes of the session.
+    pub fn play_replay(&mut self, replay: replay::Replay) {
+        self.resources.insert(if replay.is_reverse {
+            resources::Rules::Reverse { starts: Vec::new() }
+        } else {
+            resources::Rules::Forward
+        });
+        self.resources.insert(replay.animation_mode);
+        if let Some(mut control) = self.resources.get_mut::<resources::PlayerControl>() {
+            control.mode = replay.control_mode;
+        }
+        self.resources
+            .insert(resources::InputBindings::for_control_mode(
+                replay.control_mode,
+            ));
+        self.load_level(&replay.level);
+        self.scene = Scene::Replaying(replay::Playback::new(replay));
+    }
+
+    /// Save the session of the level that is being played to the replay file.
+    fn save_replay(&mut self, ctx: &mut ggez::Context) -> ggez::GameResult {
+        let level = match self.played_level() {
+            Some(level) => level.clone(),
+            None => return Ok(()),
+        };
+        let is_reverse = matches!(
+            self.resources.get::<resources::Rules>().as_deref(),
+            Some(resources::Rules::Reverse { .. })
+        );
+        let replay = match self.resources.get::<resources::ActionLog>() {
+            Some(log) => replay::Replay {
+                level,
+                is_reverse,
+                animation_mode: log.animation_mode,
+                control_mode: log.control_mode,
+                actions: log.actions.clone(),
+            },
+            None => return Ok(()),
+        };
+        filesystem::create(ctx, REPLAY_PATH)?.write_all(replay.to_text().as_bytes())?;
+        Ok(())
+    }
+
+    /// Play back the session that was last saved to the replay file.
+    fn load_replay(&mut self, ctx: &mut ggez::Context) -> ggez::GameResult {
+        let mut text = String::new();
+        filesystem::open(ctx, REPLAY_PATH)?.read_to_string(&mut text)?;
+        self.play_replay(replay::Replay::from_text(&text)?);
+        Ok(())
+    }
+
+    /// Animate the solution that is stored with the level, or the solution found by the solver,
+    /// from the start of the level. The level is shown without its challenge so that slow
+    /// demonstrations do not run out of time. Like hints, solutions are only shown when the level
+    /// is played forward. The solver runs a little at every update, see
+    /// [`Game::search_solution`].
+    fn show_solution(&mut self) {
+        let is_forward = self
+            .resources
+            .get::<resources::Rules>()
+            .is_some_and(|rules| *rules == resources::Rules::Forward);
+        if !matches!(self.scene, Scene::Playing) || !is_forward || self.solving.is_some() {
+            return;
+        }
+        match self.level.solution().map(str::to_string) {
+            Some(solution) => self.demonstrate(&solution),
+            None => {
+                self.solving = Some(solver::Solver::new(&self.level, SOLUTION_NODE_LIMIT));
+                self.show_message("Looking for a solution...".to_string());
+            }
+        }
+    }
+
+    /// Advance the search for a solution to demonstrate, then demonstrate the solution once it is
+    /// found.
+    fn search_solution(&mut self) {
+        if !matches!(self.scene, Scene::Playing) {
+            self.solving = None;
+        }
+        let search = match self
+            .solving
+            .as_mut()
+            .and_then(|solver| solver.run(SOLUTION_NODES_PER_UPDATE))
+        {
+            Some(search) => search,
+            None => return,
+        };
+        self.solving = None;
+        match search.outcome {
+            solver::Outcome::Solved(solution) => self.demonstrate(&solution.moves),
+            solver::Outcome::Unsolved => {
+                self.show_message("No solution was found for the level".to_string())
+            }
+            solver::Outcome::Unsupported => self
+                .show_message("The solver does not support levels with special floors".to_string()),
+        }
+    }
+
+    /// Animate the solution in the LURD format from the start of the level.
+    fn demonstrate(&mut self, solution: &str) {
+        match demonstration::Demonstration::from_lurd(solution) {
+            Some(demonstration) => {
+                self.load_level(&self.level.with_challenge(resources::Challenge::default()));
+                self.scene = Scene::Demonstrating(demonstration);
+            }
+            None => self.show_message("No solution was found for the level".to_string()),
+        }
+    }
+
+    /// Play back a single tick of the replay: the actions that are due are queued as if their
+    /// inputs were pressed, then a simulation step is run. Returns whether any action was due.
+    fn step_replay(&mut self) -> bool {
+        let session_tick = match self.resources.get::<resources::ActionLog>() {
+            Some(log) => self
+                .resources
+                .get::<resources::Time>()
+                .map_or(0, |time| time.ticks + 1 - log.start),
+            None => return false,
+        };
+        let actions = match &mut self.scene {
+            Scene::Replaying(playback) => playback.due(session_tick),
+            _ => return false,
+        };
+        for action in actions.iter() {
+            perform(&mut self.resources, *action);
+        }
+        tick(&mut self.world, &mut self.resources, &mut self.schedule);
+        self.record_reverse_solution();
+        !actions.is_empty()
+    }
+
+    /// Play back the replay for a single update of the game, at the speed of the playback. A
+    /// paused replay only plays when it is stepped, up to its next action.
+    fn update_replay(&mut self) {
+        let (ticks, is_stepping) = match &mut self.scene {
+            Scene::Replaying(playback) if playback.is_finished() => return,
+            Scene::Replaying(playback) if playback.is_stepping => {
+                playback.is_stepping = false;
+                (u32::MAX, true)
+            }
+            Scene::Replaying(playback) if !playback.is_paused => (playback.speed, false),
+            _ => return,
+        };
+        for _ in 0..ticks {
+            if self.step_replay() && is_stepping {
+                break;
+            }
+        }
+    }
+
+    /// Show a message for the player next to the played level.
+    fn show_message(&mut self, message: String) {
+        if let Some(mut gameplay) = self.resources.get_mut::<resources::GamePlay>() {
+            gameplay.message = Some(message);
+        }
+    }
+
+    /// Get the level that is being played, if any.
+    fn played_level(&self) -> Option<&level::Level> {
+        match &self.scene {
+            Scene::Playing => Some(&self.level),
+            Scene::TestPlaying(editor) => Some(editor.level()),
+            Scene::Replaying(playback) => Some(&playback.replay.level),
+            Scene::Demonstrating(_) => Some(&self.level),
+            _ => None,
+        }
+    }
+
+    /// Switch between playing the level forward and in reverse, restarting the level.
+    fn toggle_reverse(&mut self) {
+        let level = match self.played_level() {
+            Some(level) => level.clone(),
+            None => return,
+        };
+        if let Some(mut rules) = self.resources.get_mut::<resources::Rules>() {
+            *rules = match *rules {
+                resources::Rules::Forward => resources::Rules::Reverse { starts: Vec::new() },
+                resources::Rules::Reverse { .. } => resources::Rules::Forward,
+            };
+        }
+        self.load_level(&level);
+    }
+
+    /// Convert the moves of a level that was just solved in reverse into a forward solution.
+    fn record_reverse_solution(&mut self) {
+        let is_reverse = matches!(
+            self.resources.get::<resources::Rules>().as_deref(),
+            Some(resources::Rules::Reverse { .. })
+        );
+        let is_recorded = self
+            .resources
+            .get::<resources::GamePlay>()
+            .is_none_or(|game_play| {
+                !matches!(game_play.state, resources::GamePlayState::Won)
+                    || game_play.solution.is_some()
+            });
+        if !is_reverse || is_recorded {
+            return;
+        }
+
+        let end = <(&components::Player, &components::Position)>::query()
+            .iter(&self.world)
+            .find(|(player, _)| player.index == 0)
+            .map(|(_, position)| (position.x, position.y));
+        let moves = match self.resources.get::<resources::MoveHistory>() {
+            Some(history) => history.to_lurd(),
+            None => return,
+        };
+        let solution = match (self.played_level(), end) {
+            (Some(level), Some(end)) => solver::forward_solution(level, end, &moves),
+            _ => None,
+        };
+        if let Some(mut game_play) = self.resources.get_mut::<resources::GamePlay>() {
+            game_play.solution = solution;
+        }
+    }
+
+    /// Handle the actions that are used to choose a level in the level select scene.
+    fn select(&mut self, action: resources::Action) {
+        match action {
+            resources::Action::Race(mode) => {
+                if let Some(level) = self.level_select.selected() {
+                    match race::Race::new(level, mode) {
+                        Ok(race) => self.scene = Scene::Racing(race),
+                        Err(e) => self.level_select.message = e.to_string(),
+                    }
+                }
+            }
+            resources::Action::Confirm => {
+                if let Some(level) = self.level_select.selected().cloned() {
+                    self.load_level(&level);
+                    self.level = level;
+                    self.scene = Scene::Playing;
+                }
+            }
+            action => match action.direction() {
+                Some(resources::Direction::Up) => self.level_select.select_previous(),
+                Some(resources::Direction::Down) => self.level_select.select_next(),
+                _ => {}
+            },
+        }
+    }
+
+    /// Handle the inputs that are used to edit a level in the editor scene.
+    fn edit(&mut self, ctx: &mut ggez::Context, keycode: keyboard::KeyCode) {
+        let mut editor = match std::mem::replace(&mut self.scene, Scene::Playing) {
+            Scene::Editing(editor) => editor,
+            scene => {
+                self.scene = scene;
+                return;
+            }
+        };
+
+        if let Some(brush) = editor::Brush::from_keycode(keycode) {
+            editor.brush = brush;
+        }
+        match keycode {
+            keyboard::KeyCode::C => editor.brush = editor.brush.with_next_color(),
+            keyboard::KeyCode::Left => editor.resize_by(-1, 0),
+            keyboard::KeyCode::Right => editor.resize_by(1, 0),
+            keyboard::KeyCode::Up => editor.resize_by(0, -1),
+            keyboard::KeyCode::Down => editor.resize_by(0, 1),
+            keyboard::KeyCode::S => {
+                if let Err(err) = editor.save(ctx) {
+                    editor.message = err.to_string();
+                }
+            }
+            keyboard::KeyCode::Return if editor.validate() => {
+                let level = editor.level().clone();
+                self.load_level(&level);
+                self.scene = Scene::TestPlaying(editor);
+                return;
+            }
+            _ => {}
+        }
+        self.scene = Scene::Editing(editor);
+        if keycode == keyboard::KeyCode::Escape {
+            self.push_input(ctx, resources::Input::Key(keycode));
+        }
+    }
+
+    /// Mute the audio or change one of its volumes.
+    fn change_volume(&mut self, action: resources::Action) {
+        if let Some(mut audio_store) = self.resources.get_mut::<resources::AudioStore>() {
+            let mut volume = audio_store.volume();
+            match action {
+                resources::Action::ToggleMute => volume.is_muted = !volume.is_muted,
+                resources::Action::VolumeUp => volume.change_master(resources::VOLUME_STEP),
+                resources::Action::VolumeDown => volume.change_master(-resources::VOLUME_STEP),
+                resources::Action::EffectsVolumeUp => volume.change_effects(resources::VOLUME_STEP),
+                resources::Action::EffectsVolumeDown => {
+                    volume.change_effects(-resources::VOLUME_STEP)
+                }
+                resources::Action::MusicVolumeUp => volume.change_music(resources::VOLUME_STEP),
+                resources::Action::MusicVolumeDown => volume.change_music(-resources::VOLUME_STEP),
+                _ => return,
+            }
+            audio_store.set_volume(volume);
+        }
+    }
+
+    /// Translate the input into an action using the bindings table and perform the action. While
+    /// a replay or a solution is played back, only the actions that control the playback are
+    /// performed.
+    fn push_input(&mut self, ctx: &mut ggez::Context, input: resources::Input) {
+        let action = self
+            .resources
+            .get::<resources::InputBindings>()
+            .and_then(|bindings| bindings.action(input));
+        if let Some(action) = action.filter(|action| action.changes_volume()) {
+            self.change_volume(action);
+            return;
+        }
+        if let Some(action) = action.filter(|action| *action != resources::Action::Menu) {
+            match &mut self.scene {
+                Scene::Replaying(playback) => {
+                    playback.control(action);
+                    return;
+                }
+                Scene::Demonstrating(demonstration) => {
+                    if let Some(action) = demonstration.control(action) {
+                        perform(&mut self.resources, action);
+                    }
+                    return;
+                }
+                _ => {}
+            }
+        }
+        match action {
+            Some(resources::Action::Menu) => {
+                match std::mem::replace(&mut self.scene, Scene::Playing) {
+                    Scene::Selecting => event::quit(ctx),
+                    Scene::Playing | Scene::Racing(_) | Scene::Replaying(_) => {
+                        self.scene = Scene::Selecting
+                    }
+                    Scene::Editing(_) => {
+                        let level = self.level.clone();
+                        self.load_level(&level);
+                    }
+                    Scene::TestPlaying(editor) => self.scene = Scene::Editing(editor),
+                    Scene::Demonstrating(_) => {
+                        let level = self.level.clone();
+                        self.load_level(&level);
+                    }
+                }
+            }
+            // The editor paints square tiles, so only levels on square grids can be edited.
+            Some(resources::Action::Editor) => {
+                if let (Scene::Playing, topology::Topology::Square) =
+                    (&self.scene, self.level.topology())
+                {
+                    self.scene = Scene::Editing(editor::Editor::new(self.level.clone()));
+                }
+            }
+            Some(resources::Action::ToggleReverse) => self.toggle_reverse(),
+            Some(resources::Action::SaveReplay) => {
+                if let Err(e) = self.save_replay(ctx) {
+                    self.show_message(format!("Could not save the replay: {}", e));
+                }
+            }
+            Some(resources::Action::PlayReplay) => {
+                if let Err(e) = self.load_replay(ctx) {
+                    self.show_message(format!("Could not play the replay: {}", e));
+                }
+            }
+            Some(resources::Action::ShowSolution) => self.show_solution(),
+            Some(
+                resources::Action::PausePlayback
+                | resources::Action::StepPlayback
+                | resources::Action::StepBackPlayback
+                | resources::Action::FastForwardPlayback
+                | resources::Action::SpeedUpPlayback
+                | resources::Action::SlowDownPlayback,
+            ) => {}
+            Some(
+                action
+                @ (resources::Action::ToggleAnimation | resources::Action::ToggleControlMode),
+            ) => perform(&mut self.resources, action),
+            Some(action) => {
+                if let Scene::Selecting = self.scene {
+                    self.select(action);
+                } else {
+                    perform(&mut self.resources, action);
+                }
+            }
+            None => {}
+        }
+    }
+
+    /// Queue the action that is bound to the input and keep repeating it until the input is
+    /// released, if the action can be repeated.
+    fn press_input(&mut self, ctx: &mut ggez::Context, input: resources::Input) {
+        // The racers have their own bindings, only the menu and the volume are shared with the
+        // game.
+        if let Scene::Racing(race) = &mut self.scene {
+            let action = self
+                .resources
+                .get::<resources::InputBindings>()
+                .and_then(|bindings| bindings.action(input));
+            if !action
+                .is_some_and(|action| action == resources::Action::Menu || action.changes_volume())
+            {
+                race.press_input(input);
+                return;
+            }
+        }
+        // The moves of a replay or a solution are played back, so the live inputs are never held.
+        if let Scene::Replaying(_) | Scene::Demonstrating(_) = self.scene {
+            self.push_input(ctx, input);
+            return;
+        }
+        if let Some(held_inputs) = self.resources.get::<resources::HeldInputs>() {
+            if held_inputs.is_held(input) {
+                return;
+            }
+        }
+        self.push_input(ctx, input);
+
+        let is_repeatable = self
+            .resources
+            .get::<resources::InputBindings>()
+            .and_then(|bindings| bindings.action(input))
+            .is_some_and(resources::Action::is_repeatable);
+        if !is_repeatable {
+            return;
+        }
+
+        let repeat_at = match (
+            self.resources.get::<resources::Time>(),
+            self.resources.get::<resources::InputRepeat>(),
+        ) {
+            (Some(time), Some(repeat)) => time.alive() + repeat.delay,
+            _ => return,
+        };
+        if let Some(mut held_inputs) = self.resources.get_mut::<resources::HeldInputs>() {
+            held_inputs.press(input, repeat_at);
+        }
+    }
+
+    /// Stop repeating the action that is bound to the input.
+    fn release_input(&mut self, input: resources::Input) {
+        if let Scene::Racing(race) = &mut self.scene {
+            race.release_input(input);
+        }
+        if let Some(mut held_inputs) = self.resources.get_mut::<resources::HeldInputs>() {
+            held_inputs.release(input);
+        }
+    }
 }
 
 impl event::EventHandler for Game {
     fn update(&mut self, ctx: &mut ggez::Context) -> ggez::GameResult {
+        // The game is updated a fixed number of times per second whatever the frame rate is, and
+        // every update of a played level advances the game time by the same step. A replay
+        // advances the game time only while it is played.
         while timer::check_update_time(ctx, FPS) {
-            if let Some(mut time) = self.resources.get_mut::<resources::Time>() {
-                time.alive += timer::delta(ctx);
+            let demonstrated = match &mut self.scene {
+                Scene::Demonstrating(demonstration) => demonstration.update(),
+                _ => None,
+            };
+            if let Some(action) = demonstrated {
+                perform(&mut self.resources, action);
             }
-            self.schedule.execute(&mut self.world, &mut self.resources);
+            match &mut self.scene {
+                Scene::Selecting | Scene::Editing(_) => {}
+                Scene::Replaying(_) => self.update_replay(),
+                Scene::Racing(race) => race.update(&mut self.resources),
+                Scene::Playing | Scene::TestPlaying(_) | Scene::Demonstrating(_) => {
+                    tick(&mut self.world, &mut self.resources, &mut self.schedule);
+                    self.record_reverse_solution();
+                }
+            }
+        }
+        self.search_solution();
+        if let Scene::Selecting = self.scene {
+            self.level_select.update();
         }
         Ok(())
     }
 
     fn draw(&mut self, ctx: &mut ggez::Context) -> ggez::GameResult {
         graphics::clear(ctx, graphics::WHITE);
-        systems::render_entities(ctx, &self.world, &self.resources)?;
-        systems::render_gameplay_data(ctx, &self.resources)?;
+        match &mut self.scene {
+            Scene::Selecting => self.level_select.draw(ctx)?,
+            Scene::Editing(editor) => editor.draw(ctx, &self.resources)?,
+            Scene::Racing(race) => race.draw(ctx, &mut self.resources)?,
+            Scene::Playing | Scene::TestPlaying(_) => {
+                systems::render_entities(
+                    ctx,
+                    &self.world,
+                    &self.resources,
+                    systems::Viewport::default(),
+                )?;
+                systems::render_gameplay_data(ctx, &self.resources)?;
+            }
+            Scene::Replaying(playback) => {
+                systems::render_entities(
+                    ctx,
+                    &self.world,
+                    &self.resources,
+                    systems::Viewport::default(),
+                )?;
+                systems::render_gameplay_data(ctx, &self.resources)?;
+                draw_playback_status(ctx, &playback.status())?;
+            }
+            Scene::Demonstrating(demonstration) => {
+                systems::render_entities(
+                    ctx,
+                    &self.world,
+                    &self.resources,
+                    systems::Viewport::default(),
+                )?;
+                systems::render_gameplay_data(ctx, &self.resources)?;
+                draw_playback_status(ctx, &demonstration.status())?;
+            }
+        }
         graphics::present(ctx)
     }
 
@@ -106,19 +719,259 @@ impl event::EventHandler for Game {
         ctx: &mut ggez::Context,
         keycode: keyboard::KeyCode,
         _keymods: keyboard::KeyMods,
-        _repeat: bool,
+        repeat: bool,
     ) {
-        if keycode == keyboard::KeyCode::Escape {
-            event::quit(ctx);
+        if let Scene::Editing(_) = self.scene {
+            self.edit(ctx, keycode);
+            return;
         }
 
-        let key_pressed_events = self.resources.get_mut::<resources::KeyPressedEventQueue>();
-        if let Some(mut key_pressed_events) = key_pressed_events {
-            key_pressed_events.queue.push(keycode);
-        };
+        // Repeated key presses from the operating system are ignored since held keys are
+        // repeated by the game itself at the configured rate.
+        if !repeat {
+            self.press_input(ctx, resources::Input::Key(keycode));
+        }
+    }
+
+    fn key_up_event(
+        &mut self,
+        _ctx: &mut ggez::Context,
+        keycode: keyboard::KeyCode,
+        _keymods: keyboard::KeyMods,
+    ) {
+        self.release_input(resources::Input::Key(keycode));
+    }
+
+    fn mouse_button_down_event(
+        &mut self,
+        ctx: &mut ggez::Context,
+        button: event::MouseButton,
+        x: f32,
+        y: f32,
+    ) {
+        match (&mut self.scene, button) {
+            (Scene::Editing(editor), event::MouseButton::Left) => editor.paint_at(x, y),
+            (Scene::Editing(editor), event::MouseButton::Right) => editor.erase_at(x, y),
+            (Scene::Editing(_), _) => {}
+            _ => self.push_input(ctx, resources::Input::Mouse(button)),
+        }
+    }
+
+    fn mouse_motion_event(&mut self, ctx: &mut ggez::Context, x: f32, y: f32, _dx: f32, _dy: f32) {
+        // Tiles are painted continuously while a mouse button is dragged in the editor.
+        if let Scene::Editing(editor) = &mut self.scene {
+            if mouse::button_pressed(ctx, event::MouseButton::Left) {
+                editor.paint_at(x, y);
+            } else if mouse::button_pressed(ctx, event::MouseButton::Right) {
+                editor.erase_at(x, y);
+            }
+        }
+    }
+
+    fn gamepad_button_down_event(
+        &mut self,
+        ctx: &mut ggez::Context,
+        btn: event::Button,
+        _id: event::GamepadId,
+    ) {
+        self.press_input(ctx, resources::Input::GamepadButton(btn));
+    }
+
+    fn gamepad_button_up_event(
+        &mut self,
+        _ctx: &mut ggez::Context,
+        btn: event::Button,
+        _id: event::GamepadId,
+    ) {
+        self.release_input(resources::Input::GamepadButton(btn));
+    }
+
+    fn gamepad_axis_event(
+        &mut self,
+        ctx: &mut ggez::Context,
+        axis: event::Axis,
+        value: f32,
+        _id: event::GamepadId,
+    ) {
+        let positive = resources::Input::GamepadAxis(axis, resources::AxisDirection::Positive);
+        let negative = resources::Input::GamepadAxis(axis, resources::AxisDirection::Negative);
+        if value >= GAMEPAD_AXIS_THRESHOLD {
+            self.release_input(negative);
+            self.press_input(ctx, positive);
+        } else if value <= -GAMEPAD_AXIS_THRESHOLD {
+            self.release_input(positive);
+            self.press_input(ctx, negative);
+        } else {
+            self.release_input(positive);
+            self.release_input(negative);
+        }
+    }
+}
+
+/// Draw the status of a replay or of a solution that is played back below the gameplay data,
+/// which is centered vertically.
+fn draw_playback_status(ctx: &mut ggez::Context, status: &str) -> ggez::GameResult {
+    let text = graphics::Text::new(
+        graphics::TextFragment::new(status).color(graphics::Color::new(0.0, 0.0, 0.0, 1.0)),
+    );
+    let draw_dest = mint::Point2 {
+        x: TILE_WIDTH * MAP_WIDTH as f32 + 50.0,
+        y: ARENA_HEIGHT - text.dimensions(ctx).1 as f32 - 20.0,
+    };
+    graphics::draw(ctx, &text, graphics::DrawParam::new().dest(draw_dest))
+}
+
+/// Load the collection of levels in the XSB format from the game's resources.
+pub fn load_levels(ctx: &mut ggez::Context, path: &str) -> ggez::GameResult<Vec<level::Level>> {
+    let mut xsb = String::new();
+    filesystem::open(ctx, path)?.read_to_string(&mut xsb)?;
+    parse_levels(&xsb)
+}
+
+/// Read the collection of levels in the XSB format, so that they can be played.
+pub fn parse_levels(xsb: &str) -> ggez::GameResult<Vec<level::Level>> {
+    Ok(level::Level::from_xsb(xsb)?
+        .iter()
+        .map(level::Level::with_void_outside)
+        .collect())
+}
+
+/// Load game's map and create the entities as specified by the map.
+pub fn create_world(map_str: &str) -> ggez::GameResult<legion::World> {
+    let mut world = legion::World::default();
+    let map = parse_map(map_str);
+    entities::create_entities_from_map(&mut world, map)?;
+    Ok(world)
+}
+
+/// Initialize the shared resources that do not depend on the game's context.
+pub fn create_resources() -> legion::Resources {
+    let mut resources = legion::Resources::default();
+    resources.insert(resources::Time::new(FPS));
+    resources.insert(resources::GamePlay::default());
+    resources.insert(resources::InputBindings::default());
+    resources.insert(resources::InputRepeat::default());
+    resources.insert(resources::HeldInputs::default());
+    resources.insert(resources::ActionQueue::default());
+    resources.insert(resources::ActionLog::default());
+    resources.insert(resources::AnimationMode::default());
+    resources.insert(resources::PlayerControl::default());
+    resources.insert(resources::Rules::default());
+    resources.insert(resources::Grid::default());
+    resources.insert(resources::MoveHistory::default());
+    resources.insert(resources::GamePlayEventQueue::default());
+    resources.insert(resources::SoundBindings::default());
+    resources.insert(systems::RenderCache::default());
+    resources
+}
+
+/// Load the game's sound effects and images into memory, and add their stores to the resources.
+/// The sounds are the ones that the audio configuration binds to the events of the game, and
+/// they are played to the given output at the configured volumes.
+pub fn load_stores(
+    ctx: &mut ggez::Context,
+    resources: &mut legion::Resources,
+    audio_output: audio_backend::Output,
+) -> ggez::GameResult {
+    let config = load_audio_config(ctx)?;
+    let mut audio_store = resources::AudioStore::new(audio_output);
+    audio_store.set_volume(config.volume);
+    load_sounds(ctx, &mut audio_store, &config.sounds.paths())?;
+    resources.insert(audio_store);
+    resources.insert(config.sounds);
+
+    let mut drawable_store = resources::DrawableStore::default();
+    load_images(ctx, &mut drawable_store, IMAGES)?;
+    resources.insert(drawable_store);
+    Ok(())
+}
+
+/// Queue the action so it can be consumed by the game's systems, and record it so that the
+/// session can be played back. Actions that are not handled by the game's systems are performed
+/// immediately.
+pub fn perform(resources: &mut legion::Resources, action: resources::Action) {
+    match action {
+        resources::Action::ToggleAnimation => {
+            if let Some(mut animation_mode) = resources.get_mut::<resources::AnimationMode>() {
+                animation_mode.toggle();
+            }
+        }
+        resources::Action::ToggleControlMode => toggle_control_mode(resources),
+        action => {
+            if let Some(mut actions) = resources.get_mut::<resources::ActionQueue>() {
+                actions.queue.push_back(action);
+            }
+        }
+    }
+    // The action is queued between two ticks, so it is first seen by the next tick.
+    if let (Some(time), Some(mut log)) = (
+        resources.get::<resources::Time>(),
+        resources.get_mut::<resources::ActionLog>(),
+    ) {
+        log.record(time.ticks + 1, action);
     }
 }
 
+/// Switch between controlling the players in turn and controlling each player with its own
+/// bindings.
+pub fn toggle_control_mode(resources: &mut legion::Resources) {
+    let mode = match resources.get_mut::<resources::PlayerControl>() {
+        Some(mut control) => {
+            control.mode.toggle();
+            control.mode
+        }
+        None => return,
+    };
+    resources.insert(resources::InputBindings::for_control_mode(mode));
+    resources.insert(resources::HeldInputs::default());
+}
+
+/// Build the schedule of systems that run the game's rules at every simulation step.
+pub fn create_schedule() -> legion::Schedule {
+    legion::Schedule::builder()
+        .add_system(systems::grid_indexing_system())
+        .add_system(systems::input_repeating_system())
+        .add_system(systems::input_handling_system())
+        .add_system(systems::hint_searching_system())
+        .add_system(systems::door_switching_system())
+        .add_system(systems::game_objective_system())
+        .add_system(systems::challenge_checking_system())
+        .add_system(systems::consume_gameplay_events_system())
+        .build()
+}
+
+/// Advance the clock by one tick, then run a simulation step. The outcome of a step only depends
+/// on the ticks and the inputs, so headless games may be ticked faster than real time.
+pub fn tick(
+    world: &mut legion::World,
+    resources: &mut legion::Resources,
+    schedule: &mut legion::Schedule,
+) {
+    if let Some(mut time) = resources.get_mut::<resources::Time>() {
+        time.tick();
+    }
+    schedule.execute(world, resources);
+}
+
+/// Read the audio configuration file, or use the default configuration if there is none.
+fn load_audio_config(ctx: &mut ggez::Context) -> ggez::GameResult<resources::AudioConfig> {
+    if !filesystem::exists(ctx, AUDIO_CONFIG_PATH) {
+        return Ok(resources::AudioConfig::default());
+    }
+    let mut text = String::new();
+    filesystem::open(ctx, AUDIO_CONFIG_PATH)?.read_to_string(&mut text)?;
+    resources::AudioConfig::from_text(&text)
+}
+
+/// Load the background music into the audio store and start playing it.
+fn load_music(ctx: &mut ggez::Context, resources: &mut legion::Resources) -> ggez::GameResult {
+    if let Some(mut audio_store) = resources.get_mut::<resources::AudioStore>() {
+        audio_store.add_music(ctx, MUSIC)?;
+        audio_store.play_music()?;
+    }
+    Ok(())
+}
+
 fn load_sounds(
     ctx: &mut ggez::Context,
     audio_store: &mut resources::AudioStore,
@@ -159,3 +1012,49 @@ fn parse_map(map_str: &str) -> Vec<(components::Position, &str)> {
         })
         .collect()
 }
+
+/// A game of a level without a window, for tests. The systems run on the same resources as the
+/// windowed game, with the audio going to the null backend.
+#[cfg(test)]
+pub(crate) struct Headless {
+    pub world: legion::World,
+    pub resources: legion::Resources,
+    pub schedule: legion::Schedule,
+}
+
+#[cfg(test)]
+impl Headless {
+    pub fn new(level: &level::Level) -> Self {
+        let mut world = legion::World::default();
+        entities::create_entities_from_level(&mut world, level);
+        let mut resources = create_resources();
+        resources.insert(resources::AudioStore::default());
+        resources.insert(resources::Grid::new(level.topology()));
+        resources.insert(resources::GamePlay {
+            challenge: level.challenge(),
+            ..resources::GamePlay::default()
+        });
+        Self {
+            world,
+            resources,
+            schedule: create_schedule(),
+        }
+    }
+
+    /// Perform the action between two ticks, like the game does with the actions of its inputs.
+    pub fn queue(&mut self, action: resources::Action) {
+        perform(&mut self.resources, action);
+    }
+
+    /// Run the given number of simulation steps.
+    pub fn tick(&mut self, ticks: usize) {
+        for _ in 0..ticks {
+            tick(&mut self.world, &mut self.resources, &mut self.schedule);
+        }
+    }
+
+    /// Get the current position of the world.
+    pub fn level(&self) -> level::Level {
+        level::Level::from_world(&self.world)
+    }
+}
@@ -0,0 +1,322 @@
+use std::collections;
+
+use crate::components;
+use crate::level;
+use crate::resources;
+use crate::state;
+
+/// How many rooms are tried before giving up on finding one that fits the boxes.
+const ROOM_ATTEMPTS: usize = 100;
+
+/// How many reverse games are played in a room, the best one is kept.
+const WALK_ATTEMPTS: usize = 64;
+
+/// How many steps are taken in a reverse game.
+const WALK_STEPS: usize = 600;
+
+/// The chance that an interior tile of a room becomes a wall.
+const WALL_DENSITY: f64 = 0.2;
+
+/// The parameters of a generated level.
+#[derive(Debug, Clone)]
+pub struct Settings {
+    /// The width of the level, including its outer walls.
+    pub width: u8,
+    /// The height of the level, including its outer walls.
+    pub height: u8,
+    pub boxes: usize,
+    pub colors: usize,
+    /// The seed of the random generator, generating with the same settings always produces the
+    /// same level.
+    pub seed: u64,
+}
+
+/// A generated level, along with a solution that was found while generating it.
+#[derive(Debug, Clone)]
+pub struct Generated {
+    pub level: level::Level,
+    pub seed: u64,
+    /// The solution in the LURD format, where lowercase letters are moves and uppercase letters
+    /// are pushes.
+    pub solution: String,
+}
+
+impl Generated {
+    /// Get the number of pushes in the solution.
+    pub fn pushes(&self) -> usize {
+        self.solution
+            .chars()
+            .filter(char::is_ascii_uppercase)
+            .count()
+    }
+
+    /// Write the level in the XSB format, with its colors, seed, and solution as metadata.
+    pub fn to_xsb(&self) -> String {
+        let mut xsb = self.level.to_colored_xsb();
+        xsb.push_str(&format!("Seed: {}\n", self.seed));
+        xsb.push_str(&format!("Solution: {}\n", self.solution));
+        xsb
+    }
+}
+
+/// Generate a solvable level. A room is built at random, the boxes are placed on their box spots,
+/// then the game is played in reverse by pulling the boxes away from the box spots. The position
+/// that takes the most pushes to solve among the reverse games becomes the level, and the reverse
+/// game becomes its solution.
+pub fn generate(settings: &Settings) -> ggez::GameResult<Generated> {
+    if settings.width < 5 || settings.height < 5 {
+        return Err(ggez::GameError::ConfigError(
+            "Level must be at least 5x5!".to_string(),
+        ));
+    }
+    if settings.boxes == 0 {
+        return Err(ggez::GameError::ConfigError(
+            "Level must have at least one box!".to_string(),
+        ));
+    }
+    if settings.colors == 0 || settings.colors > components::PALETTE.len() {
+        return Err(ggez::GameError::ConfigError(format!(
+            "Level must have between 1 and {} colors!",
+            components::PALETTE.len()
+        )));
+    }
+
+    let mut rng = Rng::new(settings.seed);
+    for _ in 0..ROOM_ATTEMPTS {
+        let room = Room::new(&mut rng, settings.width, settings.height);
+        if room.floor.len() <= settings.boxes * 2 {
+            continue;
+        }
+
+        let mut best: Option<(Walk, usize)> = None;
+        for _ in 0..WALK_ATTEMPTS {
+            let walk = match room.reverse_walk(&mut rng, settings) {
+                Some(walk) => walk,
+                None => continue,
+            };
+            let score = walk.score();
+            if best
+                .as_ref()
+                .is_none_or(|(_, best_score)| score > *best_score)
+            {
+                best = Some((walk, score));
+            }
+        }
+
+        if let Some((walk, score)) = best {
+            if score > 0 {
+                return Ok(Generated {
+                    level: walk.state.to_level(),
+                    seed: settings.seed,
+                    solution: walk.solution(),
+                });
+            }
+        }
+    }
+
+    Err(ggez::GameError::ConfigError(
+        "Could not generate a level with the given settings!".to_string(),
+    ))
+}
+
+/// A room of floor tiles surrounded by walls, in which a level is generated.
+struct Room {
+    width: u8,
+    height: u8,
+    floor: Vec<(u8, u8)>,
+}
+
+impl Room {
+    /// Build a room with walls placed at random, keeping only the largest connected part of the
+    /// floor.
+    fn new(rng: &mut Rng, width: u8, height: u8) -> Self {
+        let mut candidates = collections::HashSet::new();
+        for y in 1..height - 1 {
+            for x in 1..width - 1 {
+                if rng.next_f64() >= WALL_DENSITY {
+                    candidates.insert((x, y));
+                }
+            }
+        }
+
+        let mut sorted_candidates = candidates.iter().copied().collect::<Vec<_>>();
+        sorted_candidates.sort_unstable();
+
+        let mut floor = Vec::new();
+        let mut seen = collections::HashSet::new();
+        for start in sorted_candidates {
+            let mut component = Vec::new();
+            let mut stack = vec![start];
+            while let Some((x, y)) = stack.pop() {
+                if !candidates.contains(&(x, y)) || !seen.insert((x, y)) {
+                    continue;
+                }
+                component.push((x, y));
+                stack.extend_from_slice(&[(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]);
+            }
+            if component.len() > floor.len() {
+                floor = component;
+            }
+        }
+        floor.sort_unstable();
+
+        Self {
+            width,
+            height,
+            floor,
+        }
+    }
+
+    /// Play the game in reverse from the solved position, pulling boxes away from their spots.
+    /// The reverse game is cut at the step where its position is the hardest.
+    fn reverse_walk(&self, rng: &mut Rng, settings: &Settings) -> Option<Walk> {
+        let mut free = self.floor.clone();
+        rng.shuffle(&mut free);
+
+        let mut level = self.level();
+        for (idx, (x, y)) in free.drain(..settings.boxes).enumerate() {
+            let color = components::BoxColor::Color((idx % settings.colors) as u8);
+            let tile = level.get_mut(x, y);
+            tile.the_box = Some(color);
+            tile.box_spot = Some(color);
+        }
+        let (x, y) = free[0];
+        level.get_mut(x, y).player = true;
+
+        let mut walk = Walk {
+            state: state::State::from_level(&level)?,
+            moves: Vec::new(),
+        };
+        let mut best = walk.clone();
+
+        for _ in 0..WALK_STEPS {
+            let direction = DIRECTIONS[rng.next_below(DIRECTIONS.len())];
+            let player = walk.state.player();
+            if walk
+                .state
+                .step(player, direction)
+                .is_none_or(|target| walk.state.box_at(target).is_some())
+            {
+                continue;
+            }
+
+            let is_pulling = walk
+                .state
+                .step(player, direction.opposite())
+                .is_some_and(|behind| walk.state.box_at(behind).is_some())
+                && rng.next_f64() < 0.5;
+            walk.state.pull(direction, is_pulling);
+            // Going forward, the player moves back to where it was, pushing the box if pulled.
+            walk.moves
+                .push((direction.opposite().lurd(false), is_pulling));
+
+            if walk.score() > best.score() {
+                best = walk.clone();
+            }
+        }
+        Some(best)
+    }
+
+    /// Create the empty room, with walls everywhere but on its floor.
+    fn level(&self) -> level::Level {
+        let mut level = level::Level::new(self.width, self.height);
+        for y in 0..self.height {
+            for x in 0..self.width {
+                level.get_mut(x, y).terrain = level::Terrain::Wall;
+            }
+        }
+        for (x, y) in self.floor.iter() {
+            level.get_mut(*x, *y).terrain = level::Terrain::Floor;
+        }
+        level
+    }
+}
+
+const DIRECTIONS: [resources::Direction; 4] = [
+    resources::Direction::Up,
+    resources::Direction::Down,
+    resources::Direction::Left,
+    resources::Direction::Right,
+];
+
+/// The state of a reverse game.
+#[derive(Clone)]
+struct Walk {
+    state: state::State,
+    /// The forward moves, in reverse order, along with whether they push a box.
+    moves: Vec<(char, bool)>,
+}
+
+impl Walk {
+    /// Score the position by how many boxes are away from their spots, then by the number of
+    /// pushes that are needed to solve it with the reverse game's solution.
+    fn score(&self) -> usize {
+        let misplaced = self
+            .state
+            .boxes()
+            .iter()
+            .filter(|(cell, color)| self.state.box_spot_at(*cell) != Some(*color))
+            .count();
+        let pushes = self.moves.iter().filter(|(_, is_push)| *is_push).count();
+        misplaced * WALK_STEPS + pushes
+    }
+    /// Get the solution of the position in the LURD format.
+    fn solution(&self) -> String {
+        // Moves after the last push are not needed to solve the level.
+        let first_push = self
+            .moves
+            .iter()
+            .position(|(_, is_push)| *is_push)
+            .unwrap_or(self.moves.len());
+        // Moves that are immediately walked back are removed.
+        let mut solution = String::new();
+        for (c, is_push) in self.moves[first_push..].iter().rev() {
+            if *is_push {
+                solution.push(c.to_ascii_uppercase());
+                continue;
+            }
+            match (solution.chars().last(), *c) {
+                (Some('l'), 'r') | (Some('r'), 'l') | (Some('u'), 'd') | (Some('d'), 'u') => {
+                    solution.pop();
+                }
+                _ => solution.push(*c),
+            }
+        }
+        solution
+    }
+}
+
+/// A small random number generator (SplitMix64), so that the levels generated from a seed stay
+/// the same across platforms and versions.
+struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn next_below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.next_below(i + 1);
+            items.swap(i, j);
+        }
+    }
+}
@@ -0,0 +1,1414 @@
+use legion::query::IntoQuery;
+
+use std::cmp;
+use std::collections;
+use std::fmt;
+use std::time;
+
+use crate::components;
+use crate::resources;
+use crate::topology;
+
+/// The ground of a tile in a level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Terrain {
+    /// Empty space outside of the level.
+    Void,
+    Floor,
+    Wall,
+}
+
+/// A kind of floor that changes how the entities that move onto it behave.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Special {
+    Ice,
+    OneWay(resources::Direction),
+    Hole,
+    /// A teleporter of the pair with the given number.
+    Teleporter(u8),
+    /// A switch of the group with the given number.
+    Switch(u8),
+    /// A door that is opened by the switches of the group with the given number.
+    Door(u8),
+    LockedDoor,
+    Key,
+}
+
+impl Special {
+    /// The number of groups of switches and doors, whose symbols are the letters `a` to `i`.
+    pub const GROUPS: u8 = 9;
+
+    /// Get the symbol of the special floor in the XSB format. The format has no symbols for these
+    /// floors, so they are an extension of this game. Diagonal one-way floors are written as the
+    /// vertical one that is closest to them. Teleporters are all written as `T`, their pairs are
+    /// written after the board, see [`Level::to_colored_xsb`]. Switches and doors of groups past
+    /// the last letter have no symbol.
+    pub fn xsb_symbol(self) -> Option<char> {
+        let symbol = match self {
+            Special::Ice => '~',
+            Special::OneWay(
+                resources::Direction::Up
+                | resources::Direction::UpLeft
+                | resources::Direction::UpRight,
+            ) => '^',
+            Special::OneWay(
+                resources::Direction::Down
+                | resources::Direction::DownLeft
+                | resources::Direction::DownRight,
+            ) => 'v',
+            Special::OneWay(resources::Direction::Left) => '<',
+            Special::OneWay(resources::Direction::Right) => '>',
+            Special::Hole => 'o',
+            Special::Teleporter(_) => 'T',
+            Special::Switch(group) | Special::Door(group) if group >= Self::GROUPS => return None,
+            Special::Switch(group) => (b'a' + group) as char,
+            Special::Door(group) => (b'A' + group) as char,
+            Special::LockedDoor => 'L',
+            Special::Key => 'k',
+        };
+        Some(symbol)
+    }
+
+    /// Get the special floor with the symbol in the XSB format. Teleporters are read in the first
+    /// pair, which is replaced by the pair that is written after the board.
+    pub fn from_xsb_symbol(symbol: char) -> Option<Self> {
+        match symbol {
+            '~' => Some(Special::Ice),
+            '^' => Some(Special::OneWay(resources::Direction::Up)),
+            'v' => Some(Special::OneWay(resources::Direction::Down)),
+            '<' => Some(Special::OneWay(resources::Direction::Left)),
+            '>' => Some(Special::OneWay(resources::Direction::Right)),
+            'o' => Some(Special::Hole),
+            'T' => Some(Special::Teleporter(1)),
+            'a'..='i' => Some(Special::Switch(symbol as u8 - b'a')),
+            'A'..='I' => Some(Special::Door(symbol as u8 - b'A')),
+            'L' => Some(Special::LockedDoor),
+            'k' => Some(Special::Key),
+            _ => None,
+        }
+    }
+
+    /// Get the special floor after the level is rotated clockwise by 90 degrees.
+    fn rotated(self) -> Self {
+        match self {
+            Special::OneWay(direction) => Special::OneWay(match direction {
+                resources::Direction::Up => resources::Direction::Right,
+                resources::Direction::Right => resources::Direction::Down,
+                resources::Direction::Down => resources::Direction::Left,
+                resources::Direction::Left => resources::Direction::Up,
+                resources::Direction::UpLeft => resources::Direction::UpRight,
+                resources::Direction::UpRight => resources::Direction::DownRight,
+                resources::Direction::DownRight => resources::Direction::DownLeft,
+                resources::Direction::DownLeft => resources::Direction::UpLeft,
+            }),
+            special => special,
+        }
+    }
+
+    /// Get the special floor after the level is mirrored along the vertical axis.
+    fn mirrored(self) -> Self {
+        match self {
+            Special::OneWay(direction) => Special::OneWay(match direction {
+                resources::Direction::Left => resources::Direction::Right,
+                resources::Direction::Right => resources::Direction::Left,
+                resources::Direction::UpLeft => resources::Direction::UpRight,
+                resources::Direction::UpRight => resources::Direction::UpLeft,
+                resources::Direction::DownLeft => resources::Direction::DownRight,
+                resources::Direction::DownRight => resources::Direction::DownLeft,
+                direction => direction,
+            }),
+            special => special,
+        }
+    }
+}
+
+/// A single tile of a level, along with the objects that are placed on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tile {
+    pub terrain: Terrain,
+    pub player: bool,
+    pub the_box: Option<components::BoxColor>,
+    pub box_spot: Option<components::BoxColor>,
+    /// The kind of the floor, if it is not plain.
+    pub special: Option<Special>,
+}
+
+impl Default for Tile {
+    fn default() -> Self {
+        Self {
+            terrain: Terrain::Void,
+            player: false,
+            the_box: None,
+            box_spot: None,
+            special: None,
+        }
+    }
+}
+
+impl Tile {
+    /// Get the symbol of the tile in the XSB format. Special floors are only written when there
+    /// is nothing on them, the others are written after the board, see
+    /// [`Level::to_colored_xsb`].
+    pub fn xsb_symbol(&self) -> char {
+        match (self.terrain, self.player, self.the_box, self.box_spot) {
+            (Terrain::Wall, _, _, _) => '#',
+            (Terrain::Floor, false, None, None) if self.special.is_some() => {
+                self.special.and_then(Special::xsb_symbol).unwrap_or(' ')
+            }
+            (_, true, _, None) => '@',
+            (_, true, _, Some(_)) => '+',
+            (_, false, Some(_), None) => '$',
+            (_, false, Some(_), Some(_)) => '*',
+            (_, false, None, Some(_)) => '.',
+            (Terrain::Floor, false, None, None) => ' ',
+            (Terrain::Void, false, None, None) => ' ',
+        }
+    }
+
+    /// Create a tile from its symbol in the XSB format. Empty tiles are read as floor, since the
+    /// format does not distinguish the floor from the space outside of the level.
+    pub fn from_xsb_symbol(symbol: char) -> Option<Self> {
+        let floor = Self {
+            terrain: Terrain::Floor,
+            ..Self::default()
+        };
+        let color = Some(DEFAULT_COLOR);
+        match symbol {
+            '#' => Some(Self {
+                terrain: Terrain::Wall,
+                ..Self::default()
+            }),
+            '@' => Some(Self {
+                player: true,
+                ..floor
+            }),
+            '+' => Some(Self {
+                player: true,
+                box_spot: color,
+                ..floor
+            }),
+            '$' => Some(Self {
+                the_box: color,
+                ..floor
+            }),
+            '*' => Some(Self {
+                the_box: color,
+                box_spot: color,
+                ..floor
+            }),
+            '.' => Some(Self {
+                box_spot: color,
+                ..floor
+            }),
+            ' ' | '-' | '_' => Some(floor),
+            _ => Special::from_xsb_symbol(symbol).map(|special| Self {
+                special: Some(special),
+                ..floor
+            }),
+        }
+    }
+
+    fn is_passable(&self) -> bool {
+        self.terrain == Terrain::Floor
+    }
+
+    /// Check if a player, a box or a box spot is on the tile, which hides its special floor in
+    /// the XSB format.
+    fn has_object(&self) -> bool {
+        self.player || self.the_box.is_some() || self.box_spot.is_some()
+    }
+}
+
+/// The color of the boxes and box spots in levels that do not specify their colors.
+pub const DEFAULT_COLOR: components::BoxColor = components::BoxColor::BLUE;
+
+/// A structural problem that prevents a level from being played.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    NoPlayer,
+    ColorImbalance {
+        color: components::BoxColor,
+        boxes: usize,
+        box_spots: usize,
+    },
+    /// The player can walk off the edge of the map.
+    OpenBorder,
+    /// A box that the player can never reach.
+    UnreachableBox {
+        x: u8,
+        y: u8,
+    },
+    /// A box that can never be pushed onto a box spot of its color.
+    BoxOnDeadSquare {
+        x: u8,
+        y: u8,
+    },
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Problem::NoPlayer => write!(f, "There is no player"),
+            Problem::ColorImbalance {
+                color,
+                boxes,
+                box_spots,
+            } => write!(
+                f,
+                "There are {} {} boxes but {} {} box spots",
+                boxes,
+                color_name(*color),
+                box_spots,
+                color_name(*color),
+            ),
+            Problem::OpenBorder => write!(f, "The player can walk off the map"),
+            Problem::UnreachableBox { x, y } => {
+                write!(f, "The box at ({}, {}) can not be reached", x, y)
+            }
+            Problem::BoxOnDeadSquare { x, y } => write!(
+                f,
+                "The box at ({}, {}) can not be pushed onto a box spot",
+                x, y
+            ),
+        }
+    }
+}
+
+/// A rectangular grid of tiles that describes a position of the game. The tiles are the cells of
+/// the level's topology, and the positions that are gaps between cells are void. The level may
+/// be played under the constraints of a challenge, and may come with a known solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Level {
+    width: u8,
+    height: u8,
+    tiles: Vec<Tile>,
+    topology: topology::Topology,
+    challenge: resources::Challenge,
+    /// A solution in the LURD format that is stored with the level.
+    solution: Option<String>,
+}
+
+impl Level {
+    /// Create a level on a square grid where every tile is void.
+    pub fn new(width: u8, height: u8) -> Self {
+        Self {
+            width,
+            height,
+            tiles: vec![Tile::default(); width as usize * height as usize],
+            topology: topology::Topology::default(),
+            challenge: resources::Challenge::default(),
+            solution: None,
+        }
+    }
+
+    /// Create a level of the given size where every tile is void, on the same grid and with the
+    /// same challenge as this level. The solution is not kept since the tiles change.
+    fn blank(&self, width: u8, height: u8) -> Self {
+        Self {
+            topology: self.topology,
+            challenge: self.challenge,
+            ..Self::new(width, height)
+        }
+    }
+
+    /// Create a copy of the level whose tiles are cells of the given topology.
+    pub fn with_topology(&self, topology: topology::Topology) -> Self {
+        Self {
+            topology,
+            ..self.clone()
+        }
+    }
+
+    /// Create a copy of the level that is played under the given challenge.
+    pub fn with_challenge(&self, challenge: resources::Challenge) -> Self {
+        Self {
+            challenge,
+            ..self.clone()
+        }
+    }
+
+    /// Create a copy of the level that is stored with the given solution in the LURD format.
+    pub fn with_solution(&self, solution: Option<String>) -> Self {
+        Self {
+            solution,
+            ..self.clone()
+        }
+    }
+
+    /// Create a level from the current position of the entities in the world. The world does not
+    /// know the shape of its cells, so the level is on a square grid until it is given the
+    /// topology of the game with [`Level::with_topology`].
+    pub fn from_world<W: legion::EntityStore>(world: &W) -> Self {
+        let mut query = <(
+            &components::Position,
+            &components::Renderable,
+            Option<&components::Wall>,
+            Option<&components::Player>,
+            Option<&components::Box>,
+            Option<&components::BoxSpot>,
+            legion::Entity,
+        )>::query();
+
+        // A level is at most `u8::MAX` tiles wide and high, the entities beyond are left out.
+        let (width, height) = query
+            .iter(world)
+            .fold((0, 0), |(width, height), (position, ..)| {
+                (
+                    cmp::max(width, position.x as usize + 1),
+                    cmp::max(height, position.y as usize + 1),
+                )
+            });
+        let width = cmp::min(width, u8::MAX as usize) as u8;
+        let height = cmp::min(height, u8::MAX as usize) as u8;
+        let is_inside = |position: &components::Position| position.x < width && position.y < height;
+
+        // The boxes that fill holes have become part of the floor.
+        let mut sunk_boxes = collections::HashSet::new();
+        let mut specials_query = <(
+            &components::Position,
+            Option<&components::Ice>,
+            Option<&components::OneWay>,
+            Option<&components::Hole>,
+            Option<&components::Teleporter>,
+        )>::query();
+        let mut specials = Vec::new();
+        for (position, ice, one_way, hole, teleporter) in specials_query.iter(world) {
+            let special = match (ice, one_way, hole, teleporter) {
+                (Some(_), ..) => Special::Ice,
+                (_, Some(one_way), ..) => Special::OneWay(one_way.direction),
+                (_, _, Some(components::Hole { filled_by: None }), _) => Special::Hole,
+                (
+                    _,
+                    _,
+                    Some(components::Hole {
+                        filled_by: Some(the_box),
+                    }),
+                    _,
+                ) => {
+                    sunk_boxes.insert(*the_box);
+                    continue;
+                }
+                (_, _, _, Some(teleporter)) => Special::Teleporter(teleporter.pair),
+                _ => continue,
+            };
+            specials.push(((position.x, position.y), special));
+        }
+        // Opened doors and keys that were picked up are gone from the level.
+        let mut doors_query = <(
+            &components::Position,
+            Option<&components::Switch>,
+            Option<&components::Door>,
+            Option<&components::LockedDoor>,
+            Option<&components::Key>,
+        )>::query();
+        for (position, switch, door, locked_door, key) in doors_query.iter(world) {
+            let special = match (switch, door, locked_door, key) {
+                (Some(switch), ..) => Special::Switch(switch.group),
+                (_, Some(door), ..) => Special::Door(door.group),
+                (_, _, Some(components::LockedDoor { opened_with: None }), _) => {
+                    Special::LockedDoor
+                }
+                (_, _, _, Some(components::Key { held_by: None })) => Special::Key,
+                _ => continue,
+            };
+            specials.push(((position.x, position.y), special));
+        }
+        specials.retain(|((x, y), _)| *x < width && *y < height);
+
+        let mut level = Self::new(width, height);
+        for ((x, y), special) in specials {
+            level.get_mut(x, y).special = Some(special);
+        }
+        for (position, _r, wall, player, the_box, box_spot, entity) in query.iter(world) {
+            if !is_inside(position) {
+                continue;
+            }
+            let tile = level.get_mut(position.x, position.y);
+            if wall.is_some() {
+                tile.terrain = Terrain::Wall;
+            } else if tile.terrain == Terrain::Void {
+                tile.terrain = Terrain::Floor;
+            }
+            tile.player |= player.is_some();
+            if let Some(the_box) = the_box.filter(|_| !sunk_boxes.contains(entity)) {
+                tile.the_box = Some(the_box.color);
+            }
+            if let Some(box_spot) = box_spot {
+                tile.box_spot = Some(box_spot.color);
+            }
+        }
+        level
+    }
+
+    /// Create a copy of the level with a different size. Tiles that are outside of the new size
+    /// are removed, and new tiles are void.
+    pub fn resized(&self, width: u8, height: u8) -> Self {
+        let mut level = self.blank(width, height);
+        for y in 0..cmp::min(height, self.height) {
+            for x in 0..cmp::min(width, self.width) {
+                *level.get_mut(x, y) = *self.get(x, y);
+            }
+        }
+        level
+    }
+
+    pub fn width(&self) -> u8 {
+        self.width
+    }
+
+    pub fn height(&self) -> u8 {
+        self.height
+    }
+
+    pub fn topology(&self) -> topology::Topology {
+        self.topology
+    }
+
+    pub fn challenge(&self) -> resources::Challenge {
+        self.challenge
+    }
+
+    pub fn solution(&self) -> Option<&str> {
+        self.solution.as_deref()
+    }
+
+    pub fn get(&self, x: u8, y: u8) -> &Tile {
+        &self.tiles[self.index(x, y)]
+    }
+
+    pub fn get_mut(&mut self, x: u8, y: u8) -> &mut Tile {
+        let idx = self.index(x, y);
+        &mut self.tiles[idx]
+    }
+
+    /// Get the tiles of the level along with their positions, in reading order.
+    pub fn tiles(&self) -> impl Iterator<Item = ((u8, u8), &Tile)> {
+        let width = self.width;
+        self.tiles.iter().enumerate().map(move |(idx, tile)| {
+            (
+                ((idx % width as usize) as u8, (idx / width as usize) as u8),
+                tile,
+            )
+        })
+    }
+
+    /// Read all levels from a text in the XSB format. Levels are separated by lines that are not
+    /// part of a board, such as empty lines, titles, and comments. The colors of the boxes and of
+    /// the box spots are read from the `Boxes:` and `Spots:` lines that follow a board, as written
+    /// by [`Level::to_colored_xsb`]. Boxes and box spots without a listed color use the default
+    /// color. Levels on hexagonal or triangular grids are marked by a `Grid:` line that follows
+    /// their board. The constraints of a challenge are read from the `Move limit:`, `Push limit:`,
+    /// `Time limit:` (in seconds), and `Undo: no` lines that follow a board, and a solution in the
+    /// LURD format from the `Solution:` line. The special floors under the players, the boxes,
+    /// and the box spots are read from the `Specials:` line, and the pairs of the teleporters from
+    /// the `Teleporters:` line.
+    pub fn from_xsb(xsb: &str) -> ggez::GameResult<Vec<Self>> {
+        let mut levels = Vec::new();
+        let mut board = Vec::new();
+        let mut box_colors = Vec::new();
+        let mut box_spot_colors = Vec::new();
+        let mut specials = Vec::new();
+        let mut teleporters = Vec::new();
+        let mut grid = topology::Topology::default();
+        let mut challenge = resources::Challenge::default();
+        let mut solution = None;
+        let mut is_board_finished = false;
+
+        for line in xsb.lines() {
+            let line = line.trim_end();
+            if is_board_line(line) {
+                if is_board_finished {
+                    levels.push(
+                        Self::from_board(
+                            &board,
+                            &box_colors,
+                            &box_spot_colors,
+                            &specials,
+                            &teleporters,
+                            grid,
+                        )?
+                        .with_challenge(challenge)
+                        .with_solution(solution.take()),
+                    );
+                    board.clear();
+                    box_colors.clear();
+                    box_spot_colors.clear();
+                    specials.clear();
+                    teleporters.clear();
+                    grid = topology::Topology::default();
+                    challenge = resources::Challenge::default();
+                    is_board_finished = false;
+                }
+                board.push(line);
+                continue;
+            }
+
+            is_board_finished = !board.is_empty();
+            if let Some(colors) = line.strip_prefix("Boxes:") {
+                box_colors = parse_colors(colors)?;
+            } else if let Some(colors) = line.strip_prefix("Spots:") {
+                box_spot_colors = parse_colors(colors)?;
+            } else if let Some(symbols) = line.strip_prefix("Specials:") {
+                specials = parse_specials(symbols)?;
+            } else if let Some(pairs) = line.strip_prefix("Teleporters:") {
+                teleporters = pairs
+                    .split_whitespace()
+                    .map(parse_limit)
+                    .collect::<ggez::GameResult<_>>()?;
+            } else if let Some(name) = line.strip_prefix("Grid:") {
+                grid = topology::Topology::from_name(name.trim()).ok_or_else(|| {
+                    ggez::GameError::ResourceLoadError(format!("Invalid grid {}", name.trim()))
+                })?;
+            } else if let Some(limit) = line.strip_prefix("Move limit:") {
+                challenge.max_moves = Some(parse_limit(limit)?);
+            } else if let Some(limit) = line.strip_prefix("Push limit:") {
+                challenge.max_pushes = Some(parse_limit(limit)?);
+            } else if let Some(limit) = line.strip_prefix("Time limit:") {
+                challenge.time_limit = Some(time::Duration::from_secs(parse_limit(limit)?));
+            } else if let Some(undo) = line.strip_prefix("Undo:") {
+                challenge.no_undo = undo.trim() == "no";
+            } else if let Some(moves) = line.strip_prefix("Solution:") {
+                solution = Some(moves.trim().to_string());
+            }
+        }
+        if !board.is_empty() {
+            levels.push(
+                Self::from_board(
+                    &board,
+                    &box_colors,
+                    &box_spot_colors,
+                    &specials,
+                    &teleporters,
+                    grid,
+                )?
+                .with_challenge(challenge)
+                .with_solution(solution),
+            );
+        }
+        Ok(levels)
+    }
+
+    /// Read the only level of a text in the XSB format, for tests.
+    #[cfg(test)]
+    pub(crate) fn from_test_xsb(xsb: &str) -> Self {
+        let mut levels = Self::from_xsb(xsb).expect("The level is valid");
+        assert_eq!(levels.len(), 1);
+        levels.remove(0)
+    }
+
+    /// Create a level from the rows of its board. On hexagonal grids, the symbols of the cells
+    /// are separated by spaces, and the rows are shifted so that the first wall of the board is a
+    /// cell. The colors, the special floors under the objects, and the pairs of the teleporters
+    /// are given in the reading order of the tiles that they belong to.
+    fn from_board(
+        board: &[&str],
+        box_colors: &[components::BoxColor],
+        box_spot_colors: &[components::BoxColor],
+        specials: &[Option<Special>],
+        teleporters: &[u8],
+        grid: topology::Topology,
+    ) -> ggez::GameResult<Self> {
+        let shift = board
+            .iter()
+            .enumerate()
+            .find_map(|(y, row)| row.find('#').map(|x| (x + y) % 2))
+            .filter(|_| grid == topology::Topology::Hexagonal)
+            .unwrap_or(0);
+        let width = board
+            .iter()
+            .map(|row| row.chars().count() + shift)
+            .max()
+            .unwrap_or(0);
+        if width > u8::MAX as usize || board.len() > u8::MAX as usize {
+            return Err(ggez::GameError::ResourceLoadError(
+                "Level is too large!".to_string(),
+            ));
+        }
+
+        let mut level = Self::new(width as u8, board.len() as u8).with_topology(grid);
+        let mut box_colors = box_colors.iter();
+        let mut box_spot_colors = box_spot_colors.iter();
+        let mut specials = specials.iter();
+        let mut teleporters = teleporters.iter();
+        for (y, row) in board.iter().enumerate() {
+            for (x, symbol) in row.chars().enumerate() {
+                let x = x + shift;
+                if !grid.is_cell(x as u8, y as u8) {
+                    if !matches!(symbol, ' ' | '-' | '_') {
+                        return Err(ggez::GameError::ResourceLoadError(format!(
+                            "Invalid XSB symbol {} between hexagonal cells",
+                            symbol
+                        )));
+                    }
+                    continue;
+                }
+                let mut tile = Tile::from_xsb_symbol(symbol).ok_or_else(|| {
+                    ggez::GameError::ResourceLoadError(format!("Invalid XSB symbol {}", symbol))
+                })?;
+                if tile.the_box.is_some() {
+                    tile.the_box = Some(*box_colors.next().unwrap_or(&DEFAULT_COLOR));
+                }
+                if tile.box_spot.is_some() {
+                    tile.box_spot = Some(*box_spot_colors.next().unwrap_or(&DEFAULT_COLOR));
+                }
+                if tile.has_object() {
+                    tile.special = specials.next().copied().flatten();
+                }
+                if let Some(Special::Teleporter(pair)) = &mut tile.special {
+                    *pair = *teleporters.next().unwrap_or(pair);
+                }
+                *level.get_mut(x as u8, y as u8) = tile;
+            }
+        }
+        Ok(level)
+    }
+
+    /// Create a copy of the level that is rotated clockwise by 90 degrees. Only levels on square
+    /// grids keep their shape when rotated.
+    pub fn rotated(&self) -> Self {
+        let mut level = self.blank(self.height, self.width);
+        for ((x, y), tile) in self.tiles() {
+            *level.get_mut(self.height - 1 - y, x) = Tile {
+                special: tile.special.map(Special::rotated),
+                ..*tile
+            };
+        }
+        level
+    }
+
+    /// Create a copy of the level that is mirrored along the vertical axis. Only levels on square
+    /// grids keep their shape when mirrored.
+    pub fn mirrored(&self) -> Self {
+        let mut level = self.blank(self.width, self.height);
+        for ((x, y), tile) in self.tiles() {
+            *level.get_mut(self.width - 1 - x, y) = Tile {
+                special: tile.special.map(Special::mirrored),
+                ..*tile
+            };
+        }
+        level
+    }
+
+    /// Get the positions that the players can walk to, assuming that boxes are out of the way,
+    /// and whether a player can walk off the map from any of these positions.
+    fn player_region(&self) -> (collections::HashSet<(u8, u8)>, bool) {
+        let mut region = collections::HashSet::new();
+        let mut is_open = false;
+        let mut stack = self
+            .tiles()
+            .filter(|(_, tile)| tile.player)
+            .map(|(position, _)| position)
+            .collect::<Vec<_>>();
+
+        while let Some((x, y)) = stack.pop() {
+            if !region.insert((x, y)) {
+                continue;
+            }
+            for direction in self.topology.directions() {
+                match self.neighbor(x, y, *direction) {
+                    Some((nx, ny)) => match self.get(nx, ny).terrain {
+                        Terrain::Floor => stack.push((nx, ny)),
+                        Terrain::Void => is_open = true,
+                        Terrain::Wall => {}
+                    },
+                    None => is_open = true,
+                }
+            }
+        }
+        (region, is_open)
+    }
+
+    /// Get the positions from which a box of the given color can be pushed onto one of the box
+    /// spots that match its color. These are found by pulling boxes away from the box spots.
+    pub fn live_squares(&self, color: components::BoxColor) -> collections::HashSet<(u8, u8)> {
+        let mut live = collections::HashSet::new();
+        let mut stack = self
+            .tiles()
+            .filter(|(_, tile)| tile.box_spot.is_some_and(|spot| spot.matches(color)))
+            .map(|(position, _)| position)
+            .collect::<Vec<_>>();
+
+        while let Some((x, y)) = stack.pop() {
+            if !live.insert((x, y)) {
+                continue;
+            }
+            for direction in self.topology.directions() {
+                // The box comes from the previous square, pushed by a player that stands behind it.
+                let from = self.neighbor(x, y, *direction);
+                let behind = from.and_then(|(fx, fy)| self.neighbor(fx, fy, *direction));
+                if let (Some(from), Some(behind)) = (from, behind) {
+                    if self.get(from.0, from.1).is_passable()
+                        && self.get(behind.0, behind.1).is_passable()
+                    {
+                        stack.push(from);
+                    }
+                }
+            }
+        }
+        live
+    }
+
+    /// Create the starting position of the level for playing in reverse, where the boxes are on
+    /// the box spots, and the box spots are where the boxes start. A player whose start is taken
+    /// by a box is moved to the closest free floor.
+    pub fn reversed(&self) -> Self {
+        let mut level = self.clone();
+        for ((x, y), tile) in self.tiles() {
+            let reversed = level.get_mut(x, y);
+            reversed.the_box = tile.box_spot;
+            reversed.box_spot = tile.the_box;
+        }
+
+        for ((x, y), tile) in self.tiles() {
+            if !tile.player || level.get(x, y).the_box.is_none() {
+                continue;
+            }
+            let mut seen = collections::HashSet::new();
+            let mut queue = collections::VecDeque::new();
+            queue.push_back((x, y));
+            while let Some((cx, cy)) = queue.pop_front() {
+                let candidate = level.get(cx, cy);
+                if candidate.the_box.is_none() && !candidate.player {
+                    level.get_mut(x, y).player = false;
+                    level.get_mut(cx, cy).player = true;
+                    break;
+                }
+                for (nx, ny) in level.neighbors(cx, cy) {
+                    if level.get(nx, ny).terrain == Terrain::Floor && seen.insert((nx, ny)) {
+                        queue.push_back((nx, ny));
+                    }
+                }
+            }
+        }
+        level
+    }
+
+    /// Create a copy of the level where the player at the given position is the only one, and the
+    /// other players are turned into walls, so that the level can be solved for that player.
+    pub fn with_single_player(&self, x: u8, y: u8) -> Self {
+        let mut level = self.clone();
+        for ((tx, ty), tile) in self.tiles() {
+            if tile.player && (tx, ty) != (x, y) {
+                *level.get_mut(tx, ty) = Tile {
+                    terrain: Terrain::Wall,
+                    ..Tile::default()
+                };
+            }
+        }
+        level
+    }
+
+    /// Create a copy of the level where the floor that the player can not reach is void, since the
+    /// XSB format does not distinguish the floor outside of the level from the floor inside it.
+    /// Tiles with boxes or box spots are kept as they are.
+    pub fn with_void_outside(&self) -> Self {
+        let (region, _) = self.player_region();
+        let mut level = self.clone();
+        for ((x, y), tile) in self.tiles() {
+            if tile.terrain == Terrain::Floor
+                && !region.contains(&(x, y))
+                && tile.the_box.is_none()
+                && tile.box_spot.is_none()
+            {
+                level.get_mut(x, y).terrain = Terrain::Void;
+            }
+        }
+        level
+    }
+
+    /// Create a normalized copy of the level, so that equivalent levels are written identically.
+    /// The space that the player can not reach is filled with void, the empty rows and columns
+    /// around the level are removed, and the level is rotated and mirrored into the orientation
+    /// whose XSB text comes first, preferring orientations that are wider than they are tall.
+    /// Levels on hexagonal and triangular grids keep their orientation.
+    pub fn normalized(&self) -> Self {
+        let level = self.with_void_outside().trimmed();
+        if level.topology != topology::Topology::Square {
+            return level;
+        }
+        let mut orientations = Vec::new();
+        let mut rotated = level;
+        for _ in 0..4 {
+            orientations.push(rotated.mirrored());
+            let next = rotated.rotated();
+            orientations.push(rotated);
+            rotated = next;
+        }
+        orientations
+            .into_iter()
+            .min_by_key(|level| (level.height > level.width, level.to_colored_xsb()))
+            .expect("There are eight orientations")
+    }
+
+    /// Create a copy of the level without the rows and columns of void around it. On hexagonal and
+    /// triangular grids, a row or column of void is kept when removing it would change which
+    /// positions are cells or which way the triangles point.
+    fn trimmed(&self) -> Self {
+        let occupied = self
+            .tiles()
+            .filter(|(_, tile)| **tile != Tile::default())
+            .map(|(position, _)| position)
+            .collect::<Vec<_>>();
+        if occupied.is_empty() {
+            return self.blank(0, 0);
+        }
+
+        let mut min_x = occupied.iter().map(|(x, _)| *x).min().unwrap_or(0);
+        let max_x = occupied.iter().map(|(x, _)| *x).max().unwrap_or(0);
+        let mut min_y = occupied.iter().map(|(_, y)| *y).min().unwrap_or(0);
+        let max_y = occupied.iter().map(|(_, y)| *y).max().unwrap_or(0);
+        if self.topology != topology::Topology::Square && min_x % 2 != min_y % 2 {
+            if min_x > 0 {
+                min_x -= 1;
+            } else {
+                min_y -= 1;
+            }
+        }
+
+        let mut level = self.blank(max_x - min_x + 1, max_y - min_y + 1);
+        for ((x, y), tile) in self.tiles() {
+            if (min_x..=max_x).contains(&x) && (min_y..=max_y).contains(&y) {
+                *level.get_mut(x - min_x, y - min_y) = *tile;
+            }
+        }
+        level
+    }
+
+    /// Get the positions of the void that is connected to the edges of the map, going through
+    /// void only.
+    fn outside(&self) -> collections::HashSet<(u8, u8)> {
+        let mut outside = collections::HashSet::new();
+        let mut stack = self
+            .tiles()
+            .map(|(position, _)| position)
+            .filter(|(x, y)| *x == 0 || *y == 0 || *x + 1 == self.width || *y + 1 == self.height)
+            .collect::<Vec<_>>();
+        while let Some((x, y)) = stack.pop() {
+            if *self.get(x, y) != Tile::default() || !outside.insert((x, y)) {
+                continue;
+            }
+            if x > 0 {
+                stack.push((x - 1, y));
+            }
+            if y > 0 {
+                stack.push((x, y - 1));
+            }
+            if x + 1 < self.width {
+                stack.push((x + 1, y));
+            }
+            if y + 1 < self.height {
+                stack.push((x, y + 1));
+            }
+        }
+        outside
+    }
+
+    /// Get the position of the cell next to the given position in the direction, if it is within
+    /// the level.
+    fn neighbor(&self, x: u8, y: u8, direction: resources::Direction) -> Option<(u8, u8)> {
+        self.topology
+            .neighbor((x, y), direction)
+            .filter(|(nx, ny)| *nx < self.width && *ny < self.height)
+    }
+
+    fn neighbors(&self, x: u8, y: u8) -> impl Iterator<Item = (u8, u8)> + '_ {
+        self.topology
+            .directions()
+            .iter()
+            .filter_map(move |direction| self.neighbor(x, y, *direction))
+    }
+
+    /// Check the level for structural problems. The level can be played when there is at least
+    /// one player, the players are enclosed by walls, every box spot can be given a box of a
+    /// matching color, and every box can be reached and pushed onto a box spot. Boxes that are
+    /// left over are allowed as long as they can fill the holes.
+    pub fn validate(&self) -> Vec<Problem> {
+        let mut problems = Vec::new();
+
+        let players = self.tiles().filter(|(_, tile)| tile.player).count();
+        if players == 0 {
+            problems.push(Problem::NoPlayer);
+        }
+
+        let mut counts = collections::BTreeMap::<components::BoxColor, (usize, usize)>::new();
+        for (_, tile) in self.tiles() {
+            if let Some(color) = tile.the_box {
+                counts.entry(color).or_default().0 += 1;
+            }
+            if let Some(color) = tile.box_spot {
+                counts.entry(color).or_default().1 += 1;
+            }
+        }
+        // Boxes of a color go onto box spots of the same color first, the rest need neutral boxes,
+        // wildcard box spots or holes.
+        let holes = self
+            .tiles()
+            .filter(|(_, tile)| tile.special == Some(Special::Hole))
+            .count();
+        let (any_boxes, any_box_spots) = counts
+            .get(&components::BoxColor::Any)
+            .copied()
+            .unwrap_or_default();
+        let (missing_boxes, missing_box_spots) = counts
+            .iter()
+            .filter(|(color, _)| **color != components::BoxColor::Any)
+            .fold(
+                (0, 0),
+                |(missing_boxes, missing_box_spots), (_, (boxes, box_spots))| {
+                    (
+                        missing_boxes + box_spots.saturating_sub(*boxes),
+                        missing_box_spots + boxes.saturating_sub(*box_spots),
+                    )
+                },
+            );
+        let left_over =
+            (missing_box_spots + any_boxes) as isize - (missing_boxes + any_box_spots) as isize;
+        let is_balanced = (0..=holes as isize).contains(&left_over)
+            && missing_boxes <= any_boxes
+            && missing_box_spots <= any_box_spots + holes;
+        for (color, (boxes, box_spots)) in counts.iter() {
+            if !is_balanced && boxes != box_spots {
+                problems.push(Problem::ColorImbalance {
+                    color: *color,
+                    boxes: *boxes,
+                    box_spots: *box_spots,
+                });
+            }
+        }
+
+        let mut live = collections::HashMap::new();
+        for ((x, y), tile) in self.tiles() {
+            if let Some(color) = tile.the_box {
+                let live = live
+                    .entry(color)
+                    .or_insert_with(|| self.live_squares(color));
+                if !live.is_empty() && !live.contains(&(x, y)) {
+                    problems.push(Problem::BoxOnDeadSquare { x, y });
+                }
+            }
+        }
+
+        if players == 0 {
+            return problems;
+        }
+        let (region, is_open) = self.player_region();
+        if is_open {
+            problems.push(Problem::OpenBorder);
+        }
+        for ((x, y), tile) in self.tiles() {
+            if tile.the_box.is_some() && !region.contains(&(x, y)) {
+                problems.push(Problem::UnreachableBox { x, y });
+            }
+        }
+
+        problems
+    }
+
+    /// Write the level in the XSB format, which is understood by most Sokoban programs. The
+    /// colors of the boxes and their spots are not written. The floor and the void around the
+    /// level are both written as spaces, so the void that is enclosed by the level is written as
+    /// `_` instead to tell it apart from the floor. Reading it back gives floor that the player can
+    /// not reach, which becomes void again in [`Level::with_void_outside`].
+    ///
+    /// # Examples
+    ///
+    /// ```txt
+    ///   #######
+    /// ###     #
+    /// #   $   #
+    /// #  $    #
+    /// # @     #
+    /// #    .  #
+    /// #  .    #
+    /// #       #
+    /// #########
+    /// ```
+    pub fn to_xsb(&self) -> String {
+        let outside = self.outside();
+        let mut xsb = String::new();
+        for y in 0..self.height {
+            let row = (0..self.width)
+                .map(|x| {
+                    let tile = self.get(x, y);
+                    if *tile == Tile::default()
+                        && self.topology.is_cell(x, y)
+                        && !outside.contains(&(x, y))
+                    {
+                        '_'
+                    } else {
+                        tile.xsb_symbol()
+                    }
+                })
+                .collect::<String>();
+            xsb.push_str(row.trim_end());
+            xsb.push('\n');
+        }
+        xsb
+    }
+
+    /// Write the level in the XSB format, followed by the colors of the boxes and of the box
+    /// spots. The colors are listed in the reading order of the symbols of the boxes (`$`, `*`)
+    /// and of the box spots (`.`, `*`, `+`) on the board, so that programs that do not support
+    /// colors can still read the board. The special floors under the players, the boxes, and the
+    /// box spots are listed in the reading order of these tiles, with `-` for plain floor, and the
+    /// pairs of the teleporters in the reading order of the teleporters. Levels that are not on a
+    /// square grid are followed by their grid as well, levels with a challenge by its constraints,
+    /// and levels with a stored solution by the solution.
+    ///
+    /// # Examples
+    ///
+    /// ```txt
+    /// #######
+    /// #@$ $.#
+    /// #T .~T#
+    /// #######
+    /// Boxes: blue red
+    /// Spots: red blue
+    /// Specials: - ~ - -
+    /// Teleporters: 1 2 1 2
+    /// ```
+    pub fn to_colored_xsb(&self) -> String {
+        let box_colors = self
+            .tiles()
+            .filter_map(|(_, tile)| tile.the_box)
+            .map(color_name)
+            .collect::<Vec<_>>();
+        let box_spot_colors = self
+            .tiles()
+            .filter_map(|(_, tile)| tile.box_spot)
+            .map(color_name)
+            .collect::<Vec<_>>();
+
+        let specials = self
+            .tiles()
+            .filter(|(_, tile)| tile.has_object())
+            .map(|(_, tile)| {
+                tile.special
+                    .and_then(Special::xsb_symbol)
+                    .unwrap_or('-')
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+        let teleporters = self
+            .tiles()
+            .filter_map(|(_, tile)| match tile.special {
+                Some(Special::Teleporter(pair)) => Some(pair.to_string()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let mut xsb = self.to_xsb();
+        xsb.push_str(&format!("Boxes: {}\n", box_colors.join(" ")));
+        xsb.push_str(&format!("Spots: {}\n", box_spot_colors.join(" ")));
+        if specials.iter().any(|symbol| symbol != "-") {
+            xsb.push_str(&format!("Specials: {}\n", specials.join(" ")));
+        }
+        if !teleporters.is_empty() {
+            xsb.push_str(&format!("Teleporters: {}\n", teleporters.join(" ")));
+        }
+        if self.topology != topology::Topology::Square {
+            xsb.push_str(&format!("Grid: {}\n", self.topology.name()));
+        }
+        if let Some(max_moves) = self.challenge.max_moves {
+            xsb.push_str(&format!("Move limit: {}\n", max_moves));
+        }
+        if let Some(max_pushes) = self.challenge.max_pushes {
+            xsb.push_str(&format!("Push limit: {}\n", max_pushes));
+        }
+        if let Some(time_limit) = self.challenge.time_limit {
+            xsb.push_str(&format!("Time limit: {}\n", time_limit.as_secs()));
+        }
+        if self.challenge.no_undo {
+            xsb.push_str("Undo: no\n");
+        }
+        if let Some(solution) = &self.solution {
+            xsb.push_str(&format!("Solution: {}\n", solution));
+        }
+        xsb
+    }
+
+    fn index(&self, x: u8, y: u8) -> usize {
+        y as usize * self.width as usize + x as usize
+    }
+}
+
+/// Write the current position of the world in the XSB format, including the colors of the boxes
+/// and of the box spots.
+pub fn world_to_xsb(world: &legion::World) -> String {
+    Level::from_world(world).to_colored_xsb()
+}
+
+fn is_board_line(line: &str) -> bool {
+    line.contains('#') && line.chars().all(|c| Tile::from_xsb_symbol(c).is_some())
+}
+
+fn parse_colors(colors: &str) -> ggez::GameResult<Vec<components::BoxColor>> {
+    colors
+        .split_whitespace()
+        .map(|name| {
+            color_from_name(name).ok_or_else(|| {
+                ggez::GameError::ResourceLoadError(format!("Invalid box color {}", name))
+            })
+        })
+        .collect()
+}
+
+/// Read the special floors under the objects of a level, where `-` is plain floor.
+fn parse_specials(symbols: &str) -> ggez::GameResult<Vec<Option<Special>>> {
+    symbols
+        .split_whitespace()
+        .map(|symbol| {
+            let mut chars = symbol.chars();
+            match (chars.next(), chars.next()) {
+                (Some('-'), None) => Some(None),
+                (Some(symbol), None) => Special::from_xsb_symbol(symbol).map(Some),
+                _ => None,
+            }
+            .ok_or_else(|| {
+                ggez::GameError::ResourceLoadError(format!("Invalid special floor {}", symbol))
+            })
+        })
+        .collect()
+}
+
+/// Read the number of a constraint of a challenge.
+fn parse_limit<T: std::str::FromStr>(limit: &str) -> ggez::GameResult<T> {
+    limit
+        .trim()
+        .parse()
+        .map_err(|_| ggez::GameError::ResourceLoadError(format!("Invalid limit {}", limit.trim())))
+}
+
+/// Get the color with the given name, which is either `any`, the name of a color in the palette,
+/// or the number of a color.
+fn color_from_name(name: &str) -> Option<components::BoxColor> {
+    if name == "any" {
+        return Some(components::BoxColor::Any);
+    }
+    components::PALETTE
+        .iter()
+        .position(|(palette_name, _, _)| *palette_name == name)
+        .map(|idx| idx as u8)
+        .or_else(|| name.parse().ok())
+        .map(components::BoxColor::Color)
+}
+
+/// Get the name of the color, as used in the XSB format.
+pub fn color_name(color: components::BoxColor) -> String {
+    match color {
+        components::BoxColor::Any => "any".to_string(),
+        components::BoxColor::Color(idx) => match components::PALETTE.get(idx as usize) {
+            Some((name, _, _)) => name.to_string(),
+            None => idx.to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_xsb_round_trips() {
+        let xsb = "  #######\n###  .  #\n#  $*$  #\n# @+  . #\n#########\n";
+        assert_eq!(Level::from_test_xsb(xsb).to_xsb(), xsb);
+    }
+
+    #[test]
+    fn colored_xsb_round_trips() {
+        let xsb =
+            "#######\n#@$ $.#\n#  .* #\n#######\nBoxes: blue red any\nSpots: red blue green\n";
+        let level = Level::from_test_xsb(xsb);
+        assert_eq!(level.get(2, 1).the_box, Some(components::BoxColor::BLUE));
+        assert_eq!(level.get(4, 2).the_box, Some(components::BoxColor::Any));
+        assert_eq!(level.get(3, 2).box_spot, Some(components::BoxColor::BLUE));
+        assert_eq!(level.to_colored_xsb(), xsb);
+    }
+
+    #[test]
+    fn metadata_round_trips() {
+        let xsb = "#####\n#@$.#\n#####\nBoxes: blue\nSpots: blue\nGrid: triangular\n\
+                   Move limit: 12\nPush limit: 3\nTime limit: 60\nUndo: no\nSolution: rR\n";
+        let level = Level::from_test_xsb(xsb);
+        assert_eq!(level.topology(), topology::Topology::Triangular);
+        assert_eq!(
+            level.challenge(),
+            resources::Challenge {
+                max_moves: Some(12),
+                max_pushes: Some(3),
+                time_limit: Some(time::Duration::from_secs(60)),
+                no_undo: true,
+            }
+        );
+        assert_eq!(level.solution(), Some("rR"));
+        assert_eq!(level.to_colored_xsb(), xsb);
+    }
+
+    #[test]
+    fn specials_xsb_round_trips() {
+        let xsb = "########\n#@$ .T #\n#T~ob>A#\n########\nBoxes: blue\nSpots: blue\n\
+                   Specials: ~ < T\nTeleporters: 2 3 3\n";
+        let level = Level::from_test_xsb(xsb);
+        assert_eq!(level.get(1, 1).special, Some(Special::Ice));
+        assert_eq!(
+            level.get(2, 1).special,
+            Some(Special::OneWay(resources::Direction::Left))
+        );
+        assert_eq!(level.get(4, 1).special, Some(Special::Teleporter(2)));
+        assert_eq!(level.get(5, 1).special, Some(Special::Teleporter(3)));
+        assert_eq!(level.get(1, 2).special, Some(Special::Teleporter(3)));
+        assert_eq!(level.get(4, 2).special, Some(Special::Switch(1)));
+        assert_eq!(level.get(6, 2).special, Some(Special::Door(0)));
+        assert_eq!(level.to_colored_xsb(), xsb);
+    }
+
+    #[test]
+    fn switch_and_door_symbols_round_trip() {
+        for group in 0..Special::GROUPS {
+            for special in [Special::Switch(group), Special::Door(group)] {
+                let symbol = special.xsb_symbol().expect("The group has a symbol");
+                assert_eq!(Special::from_xsb_symbol(symbol), Some(special));
+            }
+        }
+        assert_eq!(Special::Switch(Special::GROUPS).xsb_symbol(), None);
+        assert_eq!(Special::Door(u8::MAX).xsb_symbol(), None);
+    }
+
+    #[test]
+    fn hexagonal_xsb_round_trips() {
+        let xsb = "# # # # #\n # @ $ . #\n# # # # #\nBoxes: blue\nSpots: blue\nGrid: hexagonal\n";
+        let level = Level::from_test_xsb(xsb);
+        assert_eq!(level.to_colored_xsb(), xsb);
+        assert_eq!(
+            Level::from_xsb(&level.to_colored_xsb()).unwrap(),
+            vec![level]
+        );
+    }
+
+    #[test]
+    fn triangular_xsb_round_trips() {
+        let xsb = "#######\n#@$ $.#\n##  .##\n#######\nBoxes: blue blue\nSpots: blue blue\nGrid: triangular\n";
+        let level = Level::from_test_xsb(xsb);
+        assert_eq!(level.topology(), topology::Topology::Triangular);
+        assert_eq!(level.to_colored_xsb(), xsb);
+        assert_eq!(
+            Level::from_xsb(&level.to_colored_xsb()).unwrap(),
+            vec![level]
+        );
+    }
+
+    #[test]
+    fn boards_on_other_grids_are_written_as_read() {
+        for (board, topology) in [
+            (
+                "  # # # # #\n #   .     #\n# @ $   $ . #\n #     #   #\n  # # # # #\n",
+                topology::Topology::Hexagonal,
+            ),
+            ("#####\n#@$.#\n#####\n", topology::Topology::Triangular),
+        ] {
+            let xsb = format!("{}Grid: {}\n", board, topology.name());
+            let level = Level::from_test_xsb(&xsb);
+            assert_eq!(level.topology(), topology);
+            assert_eq!(level.to_xsb(), board);
+            assert_eq!(
+                Level::from_xsb(&level.to_colored_xsb()).unwrap(),
+                vec![level]
+            );
+        }
+    }
+
+    #[test]
+    fn levels_are_separated_by_other_lines() {
+        let xsb =
+            "; first\n#####\n#@$.#\n#####\n\n; second\n######\n#@$ .#\n######\nSolution: rR\n";
+        let levels = Level::from_xsb(xsb).unwrap();
+        assert_eq!(levels.len(), 2);
+        assert_eq!(levels[0].solution(), None);
+        assert_eq!(levels[1].to_xsb(), "######\n#@$ .#\n######\n");
+        assert_eq!(levels[1].solution(), Some("rR"));
+    }
+
+    #[test]
+    fn world_is_written_as_xsb() {
+        let level = Level::from_test_xsb(
+            "######\n#@$ .#\n# *  #\n######\nBoxes: red blue\nSpots: red blue\n",
+        );
+        let mut world = legion::World::default();
+        crate::entities::create_entities_from_level(&mut world, &level);
+        assert_eq!(world_to_xsb(&world), level.to_colored_xsb());
+    }
+
+    #[test]
+    fn world_beyond_the_largest_level_is_left_out() {
+        let mut world = legion::World::default();
+        for x in [0, u8::MAX - 1, u8::MAX] {
+            crate::entities::create_wall(&mut world, components::Position { x, y: 0, z: 0 });
+        }
+        crate::entities::create_wall(
+            &mut world,
+            components::Position {
+                x: 0,
+                y: u8::MAX,
+                z: 0,
+            },
+        );
+        let level = Level::from_world(&world);
+        assert_eq!((level.width(), level.height()), (u8::MAX, u8::MAX));
+        assert_eq!(level.get(u8::MAX - 1, 0).terrain, Terrain::Wall);
+    }
+
+    #[test]
+    fn invalid_xsb_is_rejected() {
+        assert!(Level::from_xsb("#####\n#@$.#\n#####\nBoxes: purple\n").is_err());
+        assert!(Level::from_xsb("#####\n#@$.#\n#####\nGrid: round\n").is_err());
+        assert!(Level::from_xsb("#####\n#@$.#\n#####\nMove limit: many\n").is_err());
+    }
+
+    #[test]
+    fn normalized_levels_are_the_same_in_every_orientation() {
+        let level = Level::from_test_xsb("    #####\n    #@  #\n    # $ #\n    #  .#\n    #####\n");
+        let normalized = level.normalized();
+        assert_eq!(normalized.to_xsb(), "#####\n#  .#\n# $ #\n#@  #\n#####\n");
+        assert_eq!(level.rotated().normalized(), normalized);
+        assert_eq!(level.mirrored().rotated().normalized(), normalized);
+    }
+
+    #[test]
+    fn enclosed_void_is_written() {
+        let level = Level::from_test_xsb("#######\n#@$.# #\n#######\n");
+        let normalized = level.normalized();
+        assert_eq!(normalized.to_xsb(), "#######\n#@$.#_#\n#######\n");
+        assert_eq!(
+            Level::from_test_xsb(&normalized.to_xsb()).normalized(),
+            normalized
+        );
+    }
+
+    #[test]
+    fn valid_levels_have_no_problems() {
+        assert_eq!(
+            Level::from_test_xsb("######\n#@$ .#\n######\n").validate(),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn problems_are_reported() {
+        assert_eq!(
+            Level::from_test_xsb("#####\n# $.#\n#####\n").validate(),
+            vec![Problem::NoPlayer]
+        );
+        assert_eq!(
+            Level::from_test_xsb("######\n#@$$.#\n######\n").validate(),
+            vec![Problem::ColorImbalance {
+                color: DEFAULT_COLOR,
+                boxes: 2,
+                box_spots: 1,
+            }]
+        );
+        assert_eq!(
+            Level::from_test_xsb("######\n#@  .#\n##$###\n").validate(),
+            vec![Problem::BoxOnDeadSquare { x: 2, y: 2 }, Problem::OpenBorder]
+        );
+    }
+}
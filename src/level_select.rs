@@ -0,0 +1,139 @@
+use ggez::graphics;
+use ggez::mint;
+
+use crate::difficulty;
+use crate::level;
+use crate::solver;
+
+/// How many positions the solver expands at every update of the level select while it estimates
+/// the difficulty of the levels, so that the level select keeps being drawn.
+const ESTIMATE_NODES_PER_UPDATE: usize = 20_000;
+
+/// The difficulty of a level, which is estimated a slice of the search at a time.
+enum Estimate {
+    Waiting,
+    Searching(Box<solver::Solver>),
+    /// The difficulty, or `None` if it can not be estimated.
+    Done(Option<difficulty::Difficulty>),
+}
+
+/// The level select scene, which lists the levels of a collection along with their difficulty,
+/// when it can be estimated.
+pub struct LevelSelect {
+    levels: Vec<(level::Level, Estimate)>,
+    selected: usize,
+    pub message: String,
+}
+
+impl LevelSelect {
+    /// Create the level select for the collection. The difficulty of the levels is estimated
+    /// later, see [`LevelSelect::update`].
+    pub fn new(levels: Vec<level::Level>) -> Self {
+        Self {
+            levels: levels
+                .into_iter()
+                .map(|level| (level, Estimate::Waiting))
+                .collect(),
+            selected: 0,
+            message: String::new(),
+        }
+    }
+
+    /// Advance the estimate of the difficulty of the selected level, or else of the first level
+    /// whose difficulty is not known yet.
+    pub fn update(&mut self) {
+        let idx = match self
+            .levels
+            .get(self.selected)
+            .filter(|(_, estimate)| !matches!(estimate, Estimate::Done(_)))
+        {
+            Some(_) => self.selected,
+            None => match self
+                .levels
+                .iter()
+                .position(|(_, estimate)| !matches!(estimate, Estimate::Done(_)))
+            {
+                Some(idx) => idx,
+                None => return,
+            },
+        };
+
+        let (level, estimate) = &mut self.levels[idx];
+        if let Estimate::Waiting = estimate {
+            *estimate =
+                Estimate::Searching(Box::new(solver::Solver::new(level, difficulty::NODE_LIMIT)));
+        }
+        if let Estimate::Searching(solver) = estimate {
+            if let Some(search) = solver.run(ESTIMATE_NODES_PER_UPDATE) {
+                *estimate = Estimate::Done(difficulty::from_search(level, &search));
+            }
+        }
+    }
+
+    pub fn selected(&self) -> Option<&level::Level> {
+        self.levels.get(self.selected).map(|(level, _)| level)
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.levels.len() {
+            self.selected += 1;
+        }
+    }
+
+    /// Draw the list of levels, highlighting the selected level.
+    ///
+    /// # Examples
+    ///
+    /// ```txt
+    /// Select a level
+    ///
+    ///   1. 9x9, 2 boxes, difficulty 31.4 (9 pushes)
+    /// > 2. 9x8, 3 boxes, difficulty 52.0 (21 pushes)
+    ///   3. 7x7, 2 boxes, difficulty unknown (special floors)
+    ///   4. 11x9, 4 boxes, difficulty estimating...
+    ///
+    /// Enter: play, V: race, T: hot-seat race, Esc: quit
+    /// ```
+    pub fn draw(&self, ctx: &mut ggez::Context) -> ggez::GameResult {
+        let text_color = graphics::Color::new(0.0, 0.0, 0.0, 1.0);
+        let mut text = graphics::Text::default();
+        text.add(graphics::TextFragment::new("Select a level\n\n").color(text_color));
+        for (idx, (level, estimate)) in self.levels.iter().enumerate() {
+            let boxes = level
+                .tiles()
+                .filter(|(_, tile)| tile.the_box.is_some())
+                .count();
+            text.add(
+                graphics::TextFragment::new(format!(
+                    "{} {}. {}x{}, {} boxes, difficulty {}\n",
+                    if idx == self.selected { ">" } else { " " },
+                    idx + 1,
+                    level.width(),
+                    level.height(),
+                    boxes,
+                    match estimate {
+                        Estimate::Done(Some(difficulty)) => difficulty.to_string(),
+                        Estimate::Done(None) => "unknown (special floors)".to_string(),
+                        Estimate::Waiting | Estimate::Searching(_) => "estimating...".to_string(),
+                    },
+                ))
+                .color(text_color),
+            );
+        }
+
+        text.add(
+            graphics::TextFragment::new(format!(
+                "\nEnter: play, V: race, T: hot-seat race, Esc: quit\n{}",
+                self.message
+            ))
+            .color(text_color),
+        );
+
+        let draw_dest = mint::Point2 { x: 20.0, y: 20.0 };
+        graphics::draw(ctx, &text, graphics::DrawParam::new().dest(draw_dest))
+    }
+}
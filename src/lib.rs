@@ -0,0 +1,17 @@
+pub mod audio_backend;
+pub mod components;
+pub mod demonstration;
+pub mod difficulty;
+pub mod editor;
+pub mod entities;
+pub mod game;
+pub mod generator;
+pub mod level;
+pub mod level_select;
+pub mod race;
+pub mod replay;
+pub mod resources;
+pub mod solver;
+pub mod state;
+pub mod systems;
+pub mod topology;
@@ -1,29 +1,44 @@
 use ggez::{conf, event};
 
 use std::env;
+use std::fs;
 use std::path;
+use std::process;
+use std::time;
 
-mod components;
-mod entities;
-mod game;
-mod resources;
-mod systems;
+use sokoban::audio_backend;
+use sokoban::difficulty;
+use sokoban::game;
+use sokoban::generator;
+use sokoban::level;
+use sokoban::replay;
 
 /// Load the game's resources and initialize the game. The path to the resources
 /// is relative to the directory that contains the project's manifest, otherwise,
-/// it is relative to the current position where the project is run.
+/// it is relative to the current position where the project is run. The levels are
+/// read from the XSB file that is given as the first argument, or from the game's
+/// default collection. The game plays without sound when there is no sound device,
+/// or when the `--no-audio` flag is given.
+///
+/// # Subcommands
+///
+/// + `validate FILE...`: report the problems of the levels in the XSB files, and
+///   print the normalized levels.
+/// + `generate WIDTH HEIGHT BOXES [COLORS] [SEED]`: print a generated level in the
+///   XSB format.
+/// + `difficulty FILE...`: print the estimated difficulty of the levels in the XSB
+///   files.
+/// + `replay FILE`: play back the session that was saved to the replay file.
 fn main() -> ggez::GameResult {
-    // TODO: Load map from persistence
-    const MAP: &str = "\
-        N N W W W W W W W
-        W W W . . . . . W
-        W . . . BB . . . W
-        W . . RB . . . . W 
-        W . P . . . . . W
-        W . . . . RS . . W
-        W . . BS . . . . W
-        W . . . . . . . W
-        W W W W W W W W W";
+    let mut args = env::args().skip(1).collect::<Vec<_>>();
+    let has_audio = !args.iter().any(|arg| arg == "--no-audio");
+    args.retain(|arg| arg != "--no-audio");
+    match args.first().map(String::as_str) {
+        Some("validate") => return validate(&args[1..]),
+        Some("generate") => return generate(&args[1..]),
+        Some("difficulty") => return estimate_difficulty(&args[1..]),
+        _ => {}
+    }
 
     let resource_dir = env::var("CARGO_MANIFEST_DIR")
         .map(|dir| {
@@ -34,11 +49,142 @@ fn main() -> ggez::GameResult {
         .unwrap_or_else(|_| path::PathBuf::from("./resources"));
     println!("Resource dir: {:?}", resource_dir);
 
-    let (ctx, evts_loop) = &mut ggez::ContextBuilder::new("sokoban", "tlv")
-        .window_setup(conf::WindowSetup::default().title("Sokoban"))
-        .window_mode(conf::WindowMode::default().dimensions(game::ARENA_WIDTH, game::ARENA_HEIGHT))
-        .add_resource_path(&resource_dir)
-        .build()?;
-    let game = &mut game::Game::new(ctx, MAP)?;
+    let (audio_output, (ctx, evts_loop)) = &mut build_context(&resource_dir, has_audio)?;
+    if let (Some("replay"), Some(path)) = (args.first().map(String::as_str), args.get(1)) {
+        let replay = replay::Replay::from_text(&fs::read_to_string(path)?)?;
+        let game = &mut game::Game::new(ctx, vec![replay.level.clone()], *audio_output)?;
+        game.play_replay(replay);
+        return event::run(ctx, evts_loop, game);
+    }
+    let levels = match args.first() {
+        Some(path) => game::parse_levels(&fs::read_to_string(path)?)?,
+        None => game::load_levels(ctx, game::DEFAULT_LEVELS)?,
+    };
+    let game = &mut game::Game::new(ctx, levels, *audio_output)?;
     event::run(ctx, evts_loop, game)
 }
+
+/// Create the game's context, along with where its audio goes. The audio module is
+/// turned off when the audio is not wanted, or when the sound device can not be
+/// initialized, so that the game still runs on machines without one.
+fn build_context(
+    resource_dir: &path::Path,
+    has_audio: bool,
+) -> ggez::GameResult<(
+    audio_backend::Output,
+    (ggez::Context, ggez::event::EventsLoop),
+)> {
+    let builder = |has_audio: bool| {
+        ggez::ContextBuilder::new("sokoban", "tlv")
+            .window_setup(conf::WindowSetup::default().title("Sokoban"))
+            .window_mode(
+                conf::WindowMode::default().dimensions(game::ARENA_WIDTH, game::ARENA_HEIGHT),
+            )
+            .modules(conf::ModuleConf::default().audio(has_audio))
+            .add_resource_path(resource_dir)
+    };
+    if !has_audio {
+        return Ok((audio_backend::Output::Null, builder(false).build()?));
+    }
+    match builder(true).build() {
+        Ok(context) => Ok((audio_backend::Output::Device, context)),
+        Err(ggez::GameError::AudioError(e)) => {
+            eprintln!("Playing without sound: {}", e);
+            Ok((audio_backend::Output::Null, builder(false).build()?))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Report the structural problems of every level in the given XSB files, then print
+/// the normalized levels to the standard output. The process exits with a failure
+/// status if any level has problems.
+fn validate(paths: &[String]) -> ggez::GameResult {
+    let mut has_problems = false;
+    for path in paths {
+        let xsb = fs::read_to_string(path)?;
+        for (idx, level) in level::Level::from_xsb(&xsb)?.iter().enumerate() {
+            let problems = level.validate();
+            for problem in problems.iter() {
+                eprintln!("{}: level {}: {}", path, idx + 1, problem);
+            }
+            has_problems |= !problems.is_empty();
+
+            println!("; {} #{}", path, idx + 1);
+            println!("{}", level.normalized().to_colored_xsb());
+        }
+    }
+
+    if has_problems {
+        process::exit(1);
+    }
+    Ok(())
+}
+
+/// Generate a level with the given size, number of boxes, number of colors, and seed,
+/// then print it in the XSB format. A seed is picked from the current time if it is
+/// not given.
+fn generate(args: &[String]) -> ggez::GameResult {
+    let arg = |idx: usize| -> ggez::GameResult<Option<u64>> {
+        args.get(idx)
+            .map(|arg| {
+                arg.parse::<u64>()
+                    .map_err(|_| ggez::GameError::ConfigError(format!("Invalid number {}", arg)))
+            })
+            .transpose()
+    };
+    let (width, height, boxes) = match (arg(0)?, arg(1)?, arg(2)?) {
+        (Some(width), Some(height), Some(boxes)) => (width, height, boxes),
+        _ => {
+            return Err(ggez::GameError::ConfigError(
+                "Usage: generate WIDTH HEIGHT BOXES [COLORS] [SEED]".to_string(),
+            ))
+        }
+    };
+    let seed = match arg(4)? {
+        Some(seed) => seed,
+        None => time::SystemTime::now()
+            .duration_since(time::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_nanos() as u64)
+            .unwrap_or_default(),
+    };
+
+    let generated = generator::generate(&generator::Settings {
+        width: width.min(u8::MAX as u64) as u8,
+        height: height.min(u8::MAX as u64) as u8,
+        boxes: boxes as usize,
+        colors: arg(3)?.unwrap_or(1) as usize,
+        seed,
+    })?;
+    print!("{}", generated.to_xsb());
+    Ok(())
+}
+
+/// Print the estimated difficulty of every level in the given XSB files.
+fn estimate_difficulty(paths: &[String]) -> ggez::GameResult {
+    for path in paths {
+        let xsb = fs::read_to_string(path)?;
+        for (idx, level) in game::parse_levels(&xsb)?.iter().enumerate() {
+            let difficulty = match difficulty::estimate(level) {
+                Some(difficulty) => difficulty,
+                None => {
+                    println!("{} #{}: unsupported (special floors)", path, idx + 1);
+                    continue;
+                }
+            };
+            println!(
+                "{} #{}: {:.1} (pushes: {}, nodes: {}, goal interactions: {}, deadlock density: {:.2})",
+                path,
+                idx + 1,
+                difficulty.score,
+                difficulty
+                    .pushes
+                    .map_or_else(|| "unsolved".to_string(), |pushes| pushes.to_string()),
+                difficulty.nodes_expanded,
+                difficulty.goal_interactions,
+                difficulty.deadlock_density,
+            );
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,413 @@
+use ggez::graphics;
+use ggez::input::keyboard;
+use ggez::mint;
+
+use crate::entities;
+use crate::game;
+use crate::level;
+use crate::resources;
+use crate::systems;
+use crate::topology;
+
+/// The space around the boards of the racers.
+const MARGIN: f32 = 12.0;
+
+/// The height that is kept below each board for the information of its racer.
+const HUD_HEIGHT: f32 = 96.0;
+
+/// The keys that move the racers up, down, left, and right, along with the key that undoes their
+/// last move, when both racers play at the same time.
+const SPLIT_KEYS: [([keyboard::KeyCode; 4], keyboard::KeyCode); 2] = [
+    (
+        [
+            keyboard::KeyCode::W,
+            keyboard::KeyCode::S,
+            keyboard::KeyCode::A,
+            keyboard::KeyCode::D,
+        ],
+        keyboard::KeyCode::Q,
+    ),
+    (
+        [
+            keyboard::KeyCode::Up,
+            keyboard::KeyCode::Down,
+            keyboard::KeyCode::Left,
+            keyboard::KeyCode::Right,
+        ],
+        keyboard::KeyCode::Back,
+    ),
+];
+
+/// The result of a race.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// The racer with the given index won.
+    Winner(usize),
+    Draw,
+}
+
+/// A racer's copy of the level, which is played with its own world, resources, and systems. The
+/// images, the sounds, and the sounds bound to the events are the ones of the game, which are lent
+/// to the racer while it runs, see `lend_stores`.
+struct Racer {
+    world: legion::World,
+    resources: legion::Resources,
+    schedule: legion::Schedule,
+    keys: String,
+    has_given_up: bool,
+}
+
+impl Racer {
+    fn new(level: &level::Level, bindings: resources::InputBindings, keys: String) -> Self {
+        let mut world = legion::World::default();
+        entities::create_entities_from_level(&mut world, level);
+        let mut resources = game::create_resources();
+        resources.insert(bindings);
+        resources.insert(resources::Grid::new(level.topology()));
+
+        Self {
+            world,
+            resources,
+            schedule: game::create_schedule(),
+            keys,
+            has_given_up: false,
+        }
+    }
+
+    fn is_solved(&self) -> bool {
+        self.resources
+            .get::<resources::GamePlay>()
+            .is_some_and(|gameplay| matches!(gameplay.state, resources::GamePlayState::Won))
+    }
+
+    fn moves(&self) -> u32 {
+        self.resources
+            .get::<resources::GamePlay>()
+            .map(|gameplay| gameplay.steps_taken)
+            .unwrap_or_default()
+    }
+
+    fn pushes(&self) -> usize {
+        self.resources
+            .get::<resources::MoveHistory>()
+            .map(|history| history.pushes())
+            .unwrap_or_default()
+    }
+
+    fn action(&self, input: resources::Input) -> Option<resources::Action> {
+        self.resources
+            .get::<resources::InputBindings>()
+            .and_then(|bindings| bindings.action(input))
+    }
+
+    /// Queue the action that is bound to the input, and keep repeating it while the input is
+    /// held down if it can be repeated.
+    fn press_input(&mut self, input: resources::Input) {
+        let action = match self.action(input) {
+            Some(action) => action,
+            None => return,
+        };
+        if self
+            .resources
+            .get::<resources::HeldInputs>()
+            .is_some_and(|held_inputs| held_inputs.is_held(input))
+        {
+            return;
+        }
+
+        if let Some(mut actions) = self.resources.get_mut::<resources::ActionQueue>() {
+            actions.queue.push_back(action);
+        }
+        if !action.is_repeatable() {
+            return;
+        }
+        let repeat_at = match (
+            self.resources.get::<resources::Time>(),
+            self.resources.get::<resources::InputRepeat>(),
+        ) {
+            (Some(time), Some(repeat)) => time.alive() + repeat.delay,
+            _ => return,
+        };
+        if let Some(mut held_inputs) = self.resources.get_mut::<resources::HeldInputs>() {
+            held_inputs.press(input, repeat_at);
+        }
+    }
+
+    fn release_input(&mut self, input: resources::Input) {
+        if let Some(mut held_inputs) = self.resources.get_mut::<resources::HeldInputs>() {
+            held_inputs.release(input);
+        }
+    }
+
+    fn update(&mut self, stores: &mut legion::Resources) {
+        lend_stores(stores, &mut self.resources);
+        game::tick(&mut self.world, &mut self.resources, &mut self.schedule);
+        lend_stores(&mut self.resources, stores);
+    }
+
+    fn draw(
+        &mut self,
+        ctx: &mut ggez::Context,
+        stores: &mut legion::Resources,
+        viewport: systems::Viewport,
+    ) -> ggez::GameResult {
+        lend_stores(stores, &mut self.resources);
+        let drawn = systems::render_entities(ctx, &self.world, &self.resources, viewport);
+        lend_stores(&mut self.resources, stores);
+        drawn
+    }
+}
+
+/// Move the stores of images and sounds from some resources to others, so that the racers use the
+/// stores that the game already loaded instead of loading their own. The sounds that the events
+/// play are moved along, so that the racers play the sounds of the audio configuration.
+fn lend_stores(from: &mut legion::Resources, to: &mut legion::Resources) {
+    if let Some(drawable_store) = from.remove::<resources::DrawableStore>() {
+        to.insert(drawable_store);
+    }
+    if let Some(audio_store) = from.remove::<resources::AudioStore>() {
+        to.insert(audio_store);
+    }
+    if let Some(sounds) = from.remove::<resources::SoundBindings>() {
+        to.insert(sounds);
+    }
+}
+
+/// The race scene, where two players play copies of the same level side by side. Each copy is run
+/// by the same systems as the game, and is drawn into its own half of the window.
+pub struct Race {
+    mode: resources::RaceMode,
+    racers: Vec<Racer>,
+    /// The racer that is playing, when the racers play one after the other.
+    turn: usize,
+    outcome: Option<Outcome>,
+}
+
+impl Race {
+    pub fn new(level: &level::Level, mode: resources::RaceMode) -> ggez::GameResult<Self> {
+        // The racers only have keys for the four directions of square grids.
+        if level.topology() != topology::Topology::Square {
+            return Err(ggez::GameError::ConfigError(
+                "Races are only played on square grids!".to_string(),
+            ));
+        }
+        let mut racers = Vec::new();
+        for (racer, ([up, down, left, right], undo)) in SPLIT_KEYS.iter().enumerate() {
+            let mut bindings = resources::InputBindings::empty();
+            let keys = match mode {
+                resources::RaceMode::SplitKeyboard => {
+                    bindings
+                        .bind(resources::Input::Key(*up), resources::Action::MoveUp)
+                        .bind(resources::Input::Key(*down), resources::Action::MoveDown)
+                        .bind(resources::Input::Key(*left), resources::Action::MoveLeft)
+                        .bind(resources::Input::Key(*right), resources::Action::MoveRight)
+                        .bind(resources::Input::Key(*undo), resources::Action::Undo);
+                    if racer == 0 {
+                        "WASD: move, Q: undo".to_string()
+                    } else {
+                        "Arrows: move, Backspace: undo".to_string()
+                    }
+                }
+                resources::RaceMode::HotSeat => {
+                    for ([up, down, left, right], _) in SPLIT_KEYS.iter() {
+                        bindings
+                            .bind(resources::Input::Key(*up), resources::Action::MoveUp)
+                            .bind(resources::Input::Key(*down), resources::Action::MoveDown)
+                            .bind(resources::Input::Key(*left), resources::Action::MoveLeft)
+                            .bind(resources::Input::Key(*right), resources::Action::MoveRight);
+                    }
+                    bindings
+                        .bind(
+                            resources::Input::Key(keyboard::KeyCode::Z),
+                            resources::Action::Undo,
+                        )
+                        .bind(
+                            resources::Input::Key(keyboard::KeyCode::X),
+                            resources::Action::GiveUp,
+                        );
+                    "Arrows/WASD: move, Z: undo, X: give up".to_string()
+                }
+            };
+            racers.push(Racer::new(level, bindings, keys));
+        }
+
+        Ok(Self {
+            mode,
+            racers,
+            turn: 0,
+            outcome: None,
+        })
+    }
+
+    /// Give the input to the racers that are playing, the racers only react to their own
+    /// bindings.
+    pub fn press_input(&mut self, input: resources::Input) {
+        if self.outcome.is_some() {
+            return;
+        }
+        match self.mode {
+            resources::RaceMode::SplitKeyboard => {
+                for racer in self.racers.iter_mut() {
+                    racer.press_input(input);
+                }
+            }
+            resources::RaceMode::HotSeat => {
+                let racer = &mut self.racers[self.turn];
+                if racer.action(input) == Some(resources::Action::GiveUp) {
+                    racer.has_given_up = true;
+                    self.end_turn();
+                } else {
+                    racer.press_input(input);
+                }
+            }
+        }
+    }
+
+    pub fn release_input(&mut self, input: resources::Input) {
+        for racer in self.racers.iter_mut() {
+            racer.release_input(input);
+        }
+    }
+
+    /// Run a simulation step for the racers that are playing with the stores of the game, then
+    /// check if the race is over.
+    pub fn update(&mut self, stores: &mut legion::Resources) {
+        if self.outcome.is_some() {
+            return;
+        }
+        match self.mode {
+            resources::RaceMode::SplitKeyboard => {
+                for racer in self.racers.iter_mut() {
+                    racer.update(stores);
+                }
+                let solved = (0..self.racers.len())
+                    .filter(|idx| self.racers[*idx].is_solved())
+                    .collect::<Vec<_>>();
+                if !solved.is_empty() {
+                    self.outcome = Some(self.fewest_pushes(&solved));
+                }
+            }
+            resources::RaceMode::HotSeat => {
+                self.racers[self.turn].update(stores);
+                if self.racers[self.turn].is_solved() {
+                    self.end_turn();
+                }
+            }
+        }
+    }
+
+    /// Leave the level to the next racer, or end the race once every racer has played. The racers
+    /// that gave up lose to the ones that solved the level, and nobody wins if they all gave up.
+    fn end_turn(&mut self) {
+        if self.turn + 1 < self.racers.len() {
+            self.turn += 1;
+            return;
+        }
+        let solved = (0..self.racers.len())
+            .filter(|idx| self.racers[*idx].is_solved())
+            .collect::<Vec<_>>();
+        self.outcome = Some(if solved.is_empty() {
+            Outcome::Draw
+        } else {
+            self.fewest_pushes(&solved)
+        });
+    }
+
+    /// Get the racer with the fewest pushes among the given racers, or a draw if several racers
+    /// share the fewest pushes.
+    fn fewest_pushes(&self, racers: &[usize]) -> Outcome {
+        let fewest = racers
+            .iter()
+            .map(|idx| self.racers[*idx].pushes())
+            .min()
+            .unwrap_or_default();
+        let best = racers
+            .iter()
+            .filter(|idx| self.racers[**idx].pushes() == fewest)
+            .collect::<Vec<_>>();
+        match best.as_slice() {
+            [winner] => Outcome::Winner(**winner),
+            _ => Outcome::Draw,
+        }
+    }
+
+    /// Draw the boards of the racers side by side with the images of the game, each with its
+    /// moves, pushes, and keys below.
+    ///
+    /// # Examples
+    ///
+    /// ```txt
+    /// Player 1             Player 2
+    /// Moves: 12            Moves: 9
+    /// Pushes: 3            Pushes: 2
+    /// WASD: move, Q: undo  Arrows: move, Backspace: undo
+    ///
+    /// Player 2 wins!
+    /// ```
+    pub fn draw(
+        &mut self,
+        ctx: &mut ggez::Context,
+        stores: &mut legion::Resources,
+    ) -> ggez::GameResult {
+        let text_color = graphics::Color::new(0.0, 0.0, 0.0, 1.0);
+        let half_width = game::ARENA_WIDTH / self.racers.len() as f32;
+        for (idx, racer) in self.racers.iter_mut().enumerate() {
+            let level = level::Level::from_world(&racer.world);
+            let tile_size = ((half_width - 2.0 * MARGIN) / level.width().max(1) as f32)
+                .min((game::ARENA_HEIGHT - HUD_HEIGHT - MARGIN) / level.height().max(1) as f32);
+            let viewport = systems::Viewport {
+                origin: mint::Point2 {
+                    x: idx as f32 * half_width + MARGIN,
+                    y: MARGIN,
+                },
+                tile_width: tile_size,
+                tile_height: tile_size,
+            };
+            racer.draw(ctx, stores, viewport)?;
+
+            let status = if racer.is_solved() {
+                "Solved"
+            } else if racer.has_given_up {
+                "Gave up"
+            } else if self.mode == resources::RaceMode::HotSeat && idx != self.turn {
+                "Waiting"
+            } else {
+                "Playing"
+            };
+            let text = graphics::Text::new(
+                graphics::TextFragment::new(format!(
+                    "Player {}: {}\nMoves: {}\nPushes: {}\n{}",
+                    idx + 1,
+                    status,
+                    racer.moves(),
+                    racer.pushes(),
+                    racer.keys,
+                ))
+                .color(text_color),
+            );
+            let draw_dest = mint::Point2 {
+                x: viewport.origin.x,
+                y: game::ARENA_HEIGHT - HUD_HEIGHT + MARGIN,
+            };
+            graphics::draw(ctx, &text, graphics::DrawParam::new().dest(draw_dest))?;
+        }
+
+        if let Some(outcome) = self.outcome {
+            let message = match outcome {
+                Outcome::Winner(idx) => format!("Player {} wins! Esc: leave", idx + 1),
+                Outcome::Draw => "Draw! Esc: leave".to_string(),
+            };
+            let text = graphics::Text::new(
+                graphics::TextFragment::new(message)
+                    .color(text_color)
+                    .scale(graphics::Scale::uniform(32.0)),
+            );
+            let draw_dest = mint::Point2 {
+                x: (game::ARENA_WIDTH - text.width(ctx) as f32) / 2.0,
+                y: (game::ARENA_HEIGHT - HUD_HEIGHT - text.height(ctx) as f32) / 2.0,
+            };
+            graphics::draw(ctx, &text, graphics::DrawParam::new().dest(draw_dest))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,333 @@
+use crate::level;
+use crate::resources;
+
+/// The speed at which a replay is played back while it is fast-forwarded, in ticks per update.
+pub const FAST_FORWARD_SPEED: u32 = 8;
+
+/// A recorded session of a level: the level, the modes that it was started with, and every
+/// action that was queued along with the tick at which it was queued, counted from the start of
+/// the session. Playing the actions back at their ticks reproduces the session.
+#[derive(Debug, Clone)]
+pub struct Replay {
+    pub level: level::Level,
+    pub is_reverse: bool,
+    pub animation_mode: resources::AnimationMode,
+    pub control_mode: resources::ControlMode,
+    pub actions: Vec<(u64, resources::Action)>,
+}
+
+impl Replay {
+    /// Write the replay as text: the level in the XSB format, the modes of the session, then one
+    /// action per line after its tick.
+    ///
+    /// # Examples
+    ///
+    /// ```txt
+    /// #######
+    /// #@$ .##
+    /// #######
+    /// Boxes: blue
+    /// Spots: blue
+    /// Rules: forward
+    /// Animation: animated
+    /// Control: switching
+    /// Actions:
+    /// 12 move r
+    /// 40 move r
+    /// 41 undo
+    /// ```
+    pub fn to_text(&self) -> String {
+        let mut text = self.level.to_colored_xsb();
+        text.push_str(if self.is_reverse {
+            "Rules: reverse\n"
+        } else {
+            "Rules: forward\n"
+        });
+        text.push_str(match self.animation_mode {
+            resources::AnimationMode::Animated => "Animation: animated\n",
+            resources::AnimationMode::Instant => "Animation: instant\n",
+        });
+        text.push_str(match self.control_mode {
+            resources::ControlMode::Switching => "Control: switching\n",
+            resources::ControlMode::Cooperative => "Control: cooperative\n",
+        });
+        text.push_str("Actions:\n");
+        for (tick, action) in self.actions.iter() {
+            if let Some(name) = action_name(*action) {
+                text.push_str(&format!("{} {}\n", tick, name));
+            }
+        }
+        text
+    }
+
+    /// Read a replay from the text written by [`Replay::to_text`].
+    pub fn from_text(text: &str) -> ggez::GameResult<Self> {
+        let (header, actions) = text.split_once("\nActions:").ok_or_else(|| {
+            ggez::GameError::ResourceLoadError("The replay has no actions".to_string())
+        })?;
+        let level = level::Level::from_xsb(header)?
+            .first()
+            .map(level::Level::with_void_outside)
+            .ok_or_else(|| {
+                ggez::GameError::ResourceLoadError("The replay has no level".to_string())
+            })?;
+
+        let mut replay = Self {
+            level,
+            is_reverse: false,
+            animation_mode: resources::AnimationMode::default(),
+            control_mode: resources::ControlMode::default(),
+            actions: Vec::new(),
+        };
+        for line in header.lines() {
+            if let Some(rules) = line.strip_prefix("Rules:") {
+                replay.is_reverse = rules.trim() == "reverse";
+            } else if let Some(animation) = line.strip_prefix("Animation:") {
+                if animation.trim() == "instant" {
+                    replay.animation_mode = resources::AnimationMode::Instant;
+                }
+            } else if let Some(control) = line.strip_prefix("Control:") {
+                if control.trim() == "cooperative" {
+                    replay.control_mode = resources::ControlMode::Cooperative;
+                }
+            }
+        }
+
+        for line in actions
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+        {
+            let invalid =
+                || ggez::GameError::ResourceLoadError(format!("Invalid replay action {}", line));
+            let (tick, name) = line.split_once(' ').ok_or_else(invalid)?;
+            let tick = tick.parse::<u64>().map_err(|_| invalid())?;
+            let action = action_from_name(name).ok_or_else(invalid)?;
+            replay.actions.push((tick, action));
+        }
+        Ok(replay)
+    }
+}
+
+/// The state of a replay that is being played back.
+#[derive(Debug)]
+pub struct Playback {
+    pub replay: Replay,
+    pub is_paused: bool,
+    /// The number of ticks that are played at every update of the game.
+    pub speed: u32,
+    /// Whether the paused replay should be played up to its next action.
+    pub is_stepping: bool,
+    next: usize,
+}
+
+impl Playback {
+    pub fn new(replay: Replay) -> Self {
+        Self {
+            replay,
+            is_paused: false,
+            speed: 1,
+            is_stepping: false,
+            next: 0,
+        }
+    }
+
+    /// Take the actions that are due at the given tick of the session.
+    pub fn due(&mut self, tick: u64) -> Vec<resources::Action> {
+        let start = self.next;
+        while self.next < self.replay.actions.len() && self.replay.actions[self.next].0 <= tick {
+            self.next += 1;
+        }
+        self.replay.actions[start..self.next]
+            .iter()
+            .map(|(_, action)| *action)
+            .collect()
+    }
+
+    /// Check if every action of the replay was played.
+    pub fn is_finished(&self) -> bool {
+        self.next >= self.replay.actions.len()
+    }
+
+    /// Handle the actions that control the playback.
+    pub fn control(&mut self, action: resources::Action) {
+        match action {
+            resources::Action::PausePlayback => self.is_paused = !self.is_paused,
+            resources::Action::StepPlayback => {
+                self.is_paused = true;
+                self.is_stepping = true;
+            }
+            resources::Action::FastForwardPlayback => {
+                self.speed = if self.speed == 1 {
+                    FAST_FORWARD_SPEED
+                } else {
+                    1
+                };
+            }
+            _ => {}
+        }
+    }
+
+    /// Describe the progress of the playback and its controls.
+    pub fn status(&self) -> String {
+        let state = if self.is_finished() {
+            "Finished"
+        } else if self.is_paused {
+            "Paused"
+        } else {
+            "Playing"
+        };
+        format!(
+            "Replay: {}/{}\n{} ({}x)\n\nSpace: pause\n.: step\nG: fast-forward",
+            self.next,
+            self.replay.actions.len(),
+            state,
+            self.speed,
+        )
+    }
+}
+
+/// Get the name of an action in a replay, if the action is recorded.
+fn action_name(action: resources::Action) -> Option<String> {
+    let name = match action {
+        resources::Action::MovePlayer(index, direction) => {
+            format!("move {} {}", direction.lurd(false), index)
+        }
+        resources::Action::SwitchPlayer => "switch".to_string(),
+        resources::Action::Undo => "undo".to_string(),
+        resources::Action::Redo => "redo".to_string(),
+        resources::Action::Restart => "restart".to_string(),
+        resources::Action::Hint => "hint".to_string(),
+        resources::Action::ToggleAnimation => "animation".to_string(),
+        resources::Action::ToggleControlMode => "control".to_string(),
+        action => format!("move {}", action.direction()?.lurd(false)),
+    };
+    Some(name)
+}
+
+/// Get the action with the given name in a replay.
+fn action_from_name(name: &str) -> Option<resources::Action> {
+    let mut words = name.split_whitespace();
+    let action = match words.next()? {
+        "move" => {
+            let direction = resources::Direction::from_lurd(words.next()?.chars().next()?)?;
+            match words.next() {
+                Some(index) => resources::Action::MovePlayer(index.parse().ok()?, direction),
+                None => match direction {
+                    resources::Direction::Up => resources::Action::MoveUp,
+                    resources::Direction::Down => resources::Action::MoveDown,
+                    resources::Direction::Left => resources::Action::MoveLeft,
+                    resources::Direction::Right => resources::Action::MoveRight,
+                    resources::Direction::UpLeft => resources::Action::MoveUpLeft,
+                    resources::Direction::UpRight => resources::Action::MoveUpRight,
+                    resources::Direction::DownLeft => resources::Action::MoveDownLeft,
+                    resources::Direction::DownRight => resources::Action::MoveDownRight,
+                },
+            }
+        }
+        "switch" => resources::Action::SwitchPlayer,
+        "undo" => resources::Action::Undo,
+        "redo" => resources::Action::Redo,
+        "restart" => resources::Action::Restart,
+        "hint" => resources::Action::Hint,
+        "animation" => resources::Action::ToggleAnimation,
+        "control" => resources::Action::ToggleControlMode,
+        _ => return None,
+    };
+    Some(action)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game;
+
+    const XSB: &str = "\
+########
+#@$  .##
+#      #
+########
+";
+
+    #[test]
+    fn text_round_trips() {
+        let replay = Replay {
+            level: level::Level::from_test_xsb(XSB),
+            is_reverse: true,
+            animation_mode: resources::AnimationMode::Instant,
+            control_mode: resources::ControlMode::Cooperative,
+            actions: vec![
+                (12, resources::Action::MoveRight),
+                (
+                    40,
+                    resources::Action::MovePlayer(1, resources::Direction::Down),
+                ),
+                (41, resources::Action::Undo),
+                (41, resources::Action::SwitchPlayer),
+                (90, resources::Action::Hint),
+            ],
+        };
+        let text = replay.to_text();
+        let read = Replay::from_text(&text).expect("The replay is valid");
+        assert_eq!(read.level.to_xsb(), replay.level.to_xsb());
+        assert!(read.is_reverse);
+        assert_eq!(read.animation_mode, replay.animation_mode);
+        assert_eq!(read.control_mode, replay.control_mode);
+        assert_eq!(read.actions, replay.actions);
+        assert_eq!(read.to_text(), text);
+    }
+
+    #[test]
+    fn playback_reaches_the_recorded_world() {
+        let ticks = 120;
+        let inputs = [
+            (5, resources::Action::MoveRight),
+            (6, resources::Action::MoveRight),
+            (30, resources::Action::Undo),
+            (50, resources::Action::MoveDown),
+            (51, resources::Action::MoveRight),
+            (52, resources::Action::MoveUp),
+        ];
+
+        // Record a session, queueing the inputs between ticks like the game does.
+        let mut game = game::Headless::new(&level::Level::from_test_xsb(XSB));
+        for tick in 0..ticks {
+            for (_, action) in inputs.iter().filter(|(at, _)| *at == tick) {
+                game.queue(*action);
+            }
+            game.tick(1);
+        }
+        let recorded = game.level().to_xsb();
+        assert_ne!(recorded, level::Level::from_test_xsb(XSB).to_xsb());
+
+        let replay = Replay {
+            level: level::Level::from_test_xsb(XSB),
+            is_reverse: false,
+            animation_mode: resources::AnimationMode::default(),
+            control_mode: resources::ControlMode::default(),
+            actions: game
+                .resources
+                .get::<resources::ActionLog>()
+                .map(|log| log.actions.clone())
+                .unwrap_or_default(),
+        };
+        assert_eq!(replay.actions.len(), inputs.len());
+
+        // Play the session back from its text.
+        let mut playback =
+            Playback::new(Replay::from_text(&replay.to_text()).expect("The replay is valid"));
+        let mut game = game::Headless::new(&playback.replay.level);
+        for _ in 0..ticks {
+            let session_tick = game
+                .resources
+                .get::<resources::Time>()
+                .map_or(0, |time| time.ticks + 1);
+            for action in playback.due(session_tick) {
+                game.queue(action);
+            }
+            game.tick(1);
+        }
+        assert!(playback.is_finished());
+        assert_eq!(game.level().to_xsb(), recorded);
+    }
+}
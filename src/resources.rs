@@ -1,40 +1,185 @@
-use ggez::audio::{self, SoundSource};
+use ggez::event;
 use ggez::graphics;
 use ggez::input::keyboard;
 
 use std::time;
 
-#[derive(Debug, Default)]
+use crate::audio_backend;
+use crate::components;
+use crate::solver;
+use crate::topology;
+
+/// The clock of the simulation, which advances by a fixed step at every tick. The game time only
+/// depends on the number of ticks, so that the game plays the same whatever the frame rate is, and
+/// can be run faster than real time.
+#[derive(Debug)]
 pub struct Time {
-    pub alive: time::Duration,
+    /// The number of ticks since the game started.
+    pub ticks: u64,
+    /// The number of ticks in a second of game time.
+    pub rate: u32,
+}
+
+impl Time {
+    pub fn new(rate: u32) -> Self {
+        Self { ticks: 0, rate }
+    }
+
+    /// Advance the clock by one step.
+    pub fn tick(&mut self) {
+        self.ticks += 1;
+    }
+
+    /// Get the game time since the game started.
+    pub fn alive(&self) -> time::Duration {
+        time::Duration::from_nanos(
+            (self.ticks as u128 * 1_000_000_000 / self.rate.max(1) as u128) as u64,
+        )
+    }
 }
 
 #[derive(Debug)]
 pub enum GamePlayState {
     Playing,
     Won,
+    /// A constraint of the level's challenge was broken before the level was won.
+    Lost(LossReason),
 }
 
 impl std::fmt::Display for GamePlayState {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            GamePlayState::Playing => f.write_str("Playing"),
+            GamePlayState::Won => f.write_str("Won"),
+            GamePlayState::Lost(reason) => write!(f, "Lost: {}", reason),
+        }
+    }
+}
+
+/// The constraint of a challenge that was broken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LossReason {
+    OutOfMoves,
+    OutOfPushes,
+    OutOfTime,
+}
+
+impl std::fmt::Display for LossReason {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         f.write_str(match self {
-            GamePlayState::Playing => "Playing",
-            GamePlayState::Won => "Won",
+            LossReason::OutOfMoves => "out of moves",
+            LossReason::OutOfPushes => "out of pushes",
+            LossReason::OutOfTime => "out of time",
         })
     }
 }
 
+/// The optional constraints under which a level is played. The game is lost once all the moves
+/// or pushes are used, or the time runs out, before the level is won.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Challenge {
+    pub max_moves: Option<u32>,
+    pub max_pushes: Option<usize>,
+    pub time_limit: Option<time::Duration>,
+    /// Moves can not be undone or redone, only the whole level can be restarted.
+    pub no_undo: bool,
+}
+
+impl Challenge {
+    /// Get the constraint that is broken after the given moves, pushes, and time, if any.
+    pub fn broken(
+        &self,
+        moves: u32,
+        pushes: usize,
+        time_taken: time::Duration,
+    ) -> Option<LossReason> {
+        if self.max_moves.is_some_and(|max_moves| moves >= max_moves) {
+            Some(LossReason::OutOfMoves)
+        } else if self
+            .max_pushes
+            .is_some_and(|max_pushes| pushes >= max_pushes)
+        {
+            Some(LossReason::OutOfPushes)
+        } else if self
+            .time_limit
+            .is_some_and(|time_limit| time_taken >= time_limit)
+        {
+            Some(LossReason::OutOfTime)
+        } else {
+            None
+        }
+    }
+
+    /// Check if the action can be performed in the state of the game. Once the game is lost,
+    /// moves can only be undone, or the level restarted.
+    pub fn allows(&self, action: Action, state: &GamePlayState) -> bool {
+        match action {
+            Action::Undo | Action::Redo if self.no_undo => false,
+            Action::Undo | Action::Restart => true,
+            _ => !matches!(state, GamePlayState::Lost(_)),
+        }
+    }
+
+    /// Describe what is left of the budget of the challenge, one constraint per line.
+    ///
+    /// # Examples
+    ///
+    /// ```txt
+    /// Moves left: 12
+    /// Time left: 1:05
+    /// No undo
+    /// ```
+    pub fn remaining(&self, moves: u32, pushes: usize, time_taken: time::Duration) -> String {
+        let mut lines = Vec::new();
+        if let Some(max_moves) = self.max_moves {
+            lines.push(format!("Moves left: {}", max_moves.saturating_sub(moves)));
+        }
+        if let Some(max_pushes) = self.max_pushes {
+            lines.push(format!(
+                "Pushes left: {}",
+                max_pushes.saturating_sub(pushes)
+            ));
+        }
+        if let Some(time_limit) = self.time_limit {
+            // The seconds are rounded up, so that the time is only up when 0:00 is shown.
+            let left = time_limit.saturating_sub(time_taken);
+            let seconds = (left.as_millis() as u64).div_ceil(1000);
+            lines.push(format!("Time left: {}:{:02}", seconds / 60, seconds % 60));
+        }
+        if self.no_undo {
+            lines.push("No undo".to_string());
+        }
+        lines.join("\n")
+    }
+}
+
 #[derive(Debug)]
 pub enum GamePlayEvent {
     HitObstacle,
     EntityMoved(legion::Entity),
     BoxSpacedOnSpot(bool),
+    /// The doors of a group were opened, or closed.
+    DoorsSwitched(bool),
+    KeyPickedUp,
+    DoorUnlocked,
 }
 
 #[derive(Debug)]
 pub struct GamePlay {
     pub state: GamePlayState,
     pub steps_taken: u32,
+    /// When the level was started or last restarted, once the game has run since then.
+    pub started_at: Option<time::Duration>,
+    /// How long the level has been played, which stops counting once the level is won.
+    pub time_taken: time::Duration,
+    /// The constraints that the level is played under, which stay when the level is restarted.
+    pub challenge: Challenge,
+    /// The forward solution in the LURD format, once the level is solved in reverse.
+    pub solution: Option<String>,
+    /// The hint that is being searched for, which is dropped once the position changes.
+    pub hint: Option<PendingHint>,
+    /// A message for the player about the last action that failed, until the next action.
+    pub message: Option<String>,
 }
 
 impl Default for GamePlay {
@@ -42,36 +187,996 @@ impl Default for GamePlay {
         Self {
             state: GamePlayState::Playing,
             steps_taken: 0,
+            started_at: None,
+            time_taken: time::Duration::ZERO,
+            challenge: Challenge::default(),
+            solution: None,
+            hint: None,
+            message: None,
         }
     }
 }
 
+/// A hint whose search is spread over the steps of the game, so that the frames are not held up
+/// while the solver runs.
+#[derive(Debug)]
+pub struct PendingHint {
+    /// The player that makes the first move of the solution.
+    pub player: usize,
+    pub solver: solver::Solver,
+}
+
 #[derive(Default)]
 pub struct GamePlayEventQueue {
     pub queue: Vec<GamePlayEvent>,
 }
 
-#[derive(Default)]
-pub struct KeyPressedEventQueue {
-    pub queue: Vec<keyboard::KeyCode>,
+/// The direction along which entities are moved on the game map. The diagonal directions are
+/// only used on hexagonal and triangular grids, see `topology::Topology`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+}
+
+impl Direction {
+    /// Get the direction of a move in the LURD format, where the case of the letter tells if the
+    /// move pushes a box. The diagonal directions are written with the letters around `w` on the
+    /// keyboard: `q`, `e`, `z`, and `c`.
+    pub fn from_lurd(symbol: char) -> Option<Self> {
+        match symbol.to_ascii_lowercase() {
+            'u' => Some(Direction::Up),
+            'd' => Some(Direction::Down),
+            'l' => Some(Direction::Left),
+            'r' => Some(Direction::Right),
+            'q' => Some(Direction::UpLeft),
+            'e' => Some(Direction::UpRight),
+            'z' => Some(Direction::DownLeft),
+            'c' => Some(Direction::DownRight),
+            _ => None,
+        }
+    }
+
+    /// Get the letter of a move in the LURD format, which is uppercase if the move pushes a box.
+    pub fn lurd(self, is_push: bool) -> char {
+        let symbol = match self {
+            Direction::Up => 'u',
+            Direction::Down => 'd',
+            Direction::Left => 'l',
+            Direction::Right => 'r',
+            Direction::UpLeft => 'q',
+            Direction::UpRight => 'e',
+            Direction::DownLeft => 'z',
+            Direction::DownRight => 'c',
+        };
+        if is_push {
+            symbol.to_ascii_uppercase()
+        } else {
+            symbol
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+            Direction::UpLeft => Direction::DownRight,
+            Direction::UpRight => Direction::DownLeft,
+            Direction::DownLeft => Direction::UpRight,
+            Direction::DownRight => Direction::UpLeft,
+        }
+    }
+}
+
+/// How the two players of a race play the level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RaceMode {
+    /// Both players play at the same time with their own keys, and the first to solve wins.
+    SplitKeyboard,
+    /// The players play one after the other with the same keys, and the fewest pushes win.
+    HotSeat,
+}
+
+/// An action that can be performed in the game. Actions are independent of the device that
+/// produced them, so the game's systems never have to deal with device-specific codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    MoveUpLeft,
+    MoveUpRight,
+    MoveDownLeft,
+    MoveDownRight,
+    /// Move the player with the given index, regardless of which player is active.
+    MovePlayer(usize, Direction),
+    SwitchPlayer,
+    Undo,
+    Redo,
+    Restart,
+    Hint,
+    Confirm,
+    ToggleAnimation,
+    ToggleControlMode,
+    ToggleReverse,
+    Race(RaceMode),
+    /// Stop trying to solve the level of a race, leaving the turn to the next racer.
+    GiveUp,
+    Editor,
+    Menu,
+    /// Save the actions of the level that is being played so that they can be played back.
+    SaveReplay,
+    /// Play back the actions that were last saved.
+    PlayReplay,
+    /// Animate a solution of the level that is being played, move by move.
+    ShowSolution,
+    /// Pause or resume the replay or the solution that is being played back.
+    PausePlayback,
+    /// Play the paused replay up to its next action, or the paused solution by one move.
+    StepPlayback,
+    /// Take back the last move of the solution that is being played back.
+    StepBackPlayback,
+    /// Switch between playing back the replay at normal and at high speed.
+    FastForwardPlayback,
+    /// Play the moves of the solution faster.
+    SpeedUpPlayback,
+    /// Play the moves of the solution slower.
+    SlowDownPlayback,
+    ToggleMute,
+    VolumeUp,
+    VolumeDown,
+    EffectsVolumeUp,
+    EffectsVolumeDown,
+    MusicVolumeUp,
+    MusicVolumeDown,
+}
+
+impl Action {
+    /// Check if the action mutes the audio or changes one of its volumes.
+    pub fn changes_volume(self) -> bool {
+        matches!(
+            self,
+            Action::ToggleMute
+                | Action::VolumeUp
+                | Action::VolumeDown
+                | Action::EffectsVolumeUp
+                | Action::EffectsVolumeDown
+                | Action::MusicVolumeUp
+                | Action::MusicVolumeDown
+        )
+    }
+
+    /// Get the direction of the movement if the action is a movement.
+    pub fn direction(self) -> Option<Direction> {
+        match self {
+            Action::MoveUp => Some(Direction::Up),
+            Action::MoveDown => Some(Direction::Down),
+            Action::MoveLeft => Some(Direction::Left),
+            Action::MoveRight => Some(Direction::Right),
+            Action::MoveUpLeft => Some(Direction::UpLeft),
+            Action::MoveUpRight => Some(Direction::UpRight),
+            Action::MoveDownLeft => Some(Direction::DownLeft),
+            Action::MoveDownRight => Some(Direction::DownRight),
+            Action::MovePlayer(_, direction) => Some(direction),
+            _ => None,
+        }
+    }
+
+    /// Check if the action is repeated while its input is held down.
+    pub fn is_repeatable(self) -> bool {
+        self.direction().is_some() || self == Action::Undo || self == Action::Redo
+    }
+}
+
+/// The direction in which a gamepad's axis is pushed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AxisDirection {
+    Positive,
+    Negative,
 }
 
+/// A device-specific input that can be bound to an action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Input {
+    Key(keyboard::KeyCode),
+    Mouse(event::MouseButton),
+    GamepadButton(event::Button),
+    GamepadAxis(event::Axis, AxisDirection),
+}
+
+/// The table that maps device-specific inputs to the game's actions.
+pub struct InputBindings {
+    bindings: std::collections::HashMap<Input, Action>,
+}
+
+impl Default for InputBindings {
+    fn default() -> Self {
+        let mut bindings = Self::empty();
+        bindings
+            .bind(Input::Key(keyboard::KeyCode::Up), Action::MoveUp)
+            .bind(Input::Key(keyboard::KeyCode::Down), Action::MoveDown)
+            .bind(Input::Key(keyboard::KeyCode::Left), Action::MoveLeft)
+            .bind(Input::Key(keyboard::KeyCode::Right), Action::MoveRight)
+            .bind(Input::Key(keyboard::KeyCode::W), Action::MoveUp)
+            .bind(Input::Key(keyboard::KeyCode::S), Action::MoveDown)
+            .bind(Input::Key(keyboard::KeyCode::A), Action::MoveLeft)
+            .bind(Input::Key(keyboard::KeyCode::D), Action::MoveRight)
+            .bind(Input::Key(keyboard::KeyCode::Home), Action::MoveUpLeft)
+            .bind(Input::Key(keyboard::KeyCode::PageUp), Action::MoveUpRight)
+            .bind(Input::Key(keyboard::KeyCode::End), Action::MoveDownLeft)
+            .bind(
+                Input::Key(keyboard::KeyCode::PageDown),
+                Action::MoveDownRight,
+            )
+            .bind(Input::Key(keyboard::KeyCode::Numpad7), Action::MoveUpLeft)
+            .bind(Input::Key(keyboard::KeyCode::Numpad9), Action::MoveUpRight)
+            .bind(Input::Key(keyboard::KeyCode::Numpad1), Action::MoveDownLeft)
+            .bind(
+                Input::Key(keyboard::KeyCode::Numpad3),
+                Action::MoveDownRight,
+            )
+            .bind(Input::Key(keyboard::KeyCode::Z), Action::Undo)
+            .bind(Input::Key(keyboard::KeyCode::Y), Action::Redo)
+            .bind(Input::Key(keyboard::KeyCode::R), Action::Restart)
+            .bind(Input::Key(keyboard::KeyCode::H), Action::Hint)
+            .bind(Input::Key(keyboard::KeyCode::Return), Action::Confirm)
+            .bind(Input::Key(keyboard::KeyCode::Tab), Action::SwitchPlayer)
+            .bind(Input::Key(keyboard::KeyCode::F), Action::ToggleAnimation)
+            .bind(Input::Key(keyboard::KeyCode::M), Action::ToggleControlMode)
+            .bind(Input::Key(keyboard::KeyCode::B), Action::ToggleReverse)
+            .bind(
+                Input::Key(keyboard::KeyCode::V),
+                Action::Race(RaceMode::SplitKeyboard),
+            )
+            .bind(
+                Input::Key(keyboard::KeyCode::T),
+                Action::Race(RaceMode::HotSeat),
+            )
+            .bind(Input::Key(keyboard::KeyCode::E), Action::Editor)
+            .bind(Input::Key(keyboard::KeyCode::Escape), Action::Menu)
+            .bind(Input::Key(keyboard::KeyCode::F2), Action::SaveReplay)
+            .bind(Input::Key(keyboard::KeyCode::F3), Action::PlayReplay)
+            .bind(Input::Key(keyboard::KeyCode::F4), Action::ShowSolution)
+            .bind(Input::Key(keyboard::KeyCode::Space), Action::PausePlayback)
+            .bind(Input::Key(keyboard::KeyCode::Period), Action::StepPlayback)
+            .bind(
+                Input::Key(keyboard::KeyCode::Comma),
+                Action::StepBackPlayback,
+            )
+            .bind(
+                Input::Key(keyboard::KeyCode::G),
+                Action::FastForwardPlayback,
+            )
+            .bind(
+                Input::Key(keyboard::KeyCode::Equals),
+                Action::SpeedUpPlayback,
+            )
+            .bind(Input::Key(keyboard::KeyCode::Add), Action::SpeedUpPlayback)
+            .bind(
+                Input::Key(keyboard::KeyCode::Minus),
+                Action::SlowDownPlayback,
+            )
+            .bind(
+                Input::Key(keyboard::KeyCode::Subtract),
+                Action::SlowDownPlayback,
+            )
+            .bind(Input::Key(keyboard::KeyCode::N), Action::ToggleMute)
+            .bind(Input::Key(keyboard::KeyCode::RBracket), Action::VolumeUp)
+            .bind(Input::Key(keyboard::KeyCode::LBracket), Action::VolumeDown)
+            .bind(
+                Input::Key(keyboard::KeyCode::Apostrophe),
+                Action::EffectsVolumeUp,
+            )
+            .bind(
+                Input::Key(keyboard::KeyCode::Semicolon),
+                Action::EffectsVolumeDown,
+            )
+            .bind(Input::Key(keyboard::KeyCode::Key0), Action::MusicVolumeUp)
+            .bind(Input::Key(keyboard::KeyCode::Key9), Action::MusicVolumeDown)
+            .bind(Input::Mouse(event::MouseButton::Right), Action::Undo)
+            .bind(Input::GamepadButton(event::Button::DPadUp), Action::MoveUp)
+            .bind(
+                Input::GamepadButton(event::Button::DPadDown),
+                Action::MoveDown,
+            )
+            .bind(
+                Input::GamepadButton(event::Button::DPadLeft),
+                Action::MoveLeft,
+            )
+            .bind(
+                Input::GamepadButton(event::Button::DPadRight),
+                Action::MoveRight,
+            )
+            .bind(Input::GamepadButton(event::Button::West), Action::Undo)
+            .bind(Input::GamepadButton(event::Button::North), Action::Redo)
+            .bind(Input::GamepadButton(event::Button::Select), Action::Restart)
+            .bind(Input::GamepadButton(event::Button::East), Action::Hint)
+            .bind(Input::GamepadButton(event::Button::South), Action::Confirm)
+            .bind(
+                Input::GamepadButton(event::Button::RightTrigger),
+                Action::SwitchPlayer,
+            )
+            .bind(Input::GamepadButton(event::Button::Start), Action::Menu);
+        // The y-axis of a gamepad's stick points upward.
+        for (axis_x, axis_y) in &[
+            (event::Axis::LeftStickX, event::Axis::LeftStickY),
+            (event::Axis::DPadX, event::Axis::DPadY),
+        ] {
+            bindings
+                .bind(
+                    Input::GamepadAxis(*axis_y, AxisDirection::Positive),
+                    Action::MoveUp,
+                )
+                .bind(
+                    Input::GamepadAxis(*axis_y, AxisDirection::Negative),
+                    Action::MoveDown,
+                )
+                .bind(
+                    Input::GamepadAxis(*axis_x, AxisDirection::Negative),
+                    Action::MoveLeft,
+                )
+                .bind(
+                    Input::GamepadAxis(*axis_x, AxisDirection::Positive),
+                    Action::MoveRight,
+                );
+        }
+        bindings
+    }
+}
+
+impl InputBindings {
+    /// Create a table without any bindings.
+    pub fn empty() -> Self {
+        Self {
+            bindings: std::collections::HashMap::new(),
+        }
+    }
+
+    /// Get the bindings for the control mode. In the cooperative mode, each of the first players
+    /// is moved with its own keys: WASD, the arrows, IJKL, and the numpad.
+    pub fn for_control_mode(mode: ControlMode) -> Self {
+        let mut bindings = Self::default();
+        if mode == ControlMode::Cooperative {
+            let keys = [
+                [
+                    keyboard::KeyCode::W,
+                    keyboard::KeyCode::S,
+                    keyboard::KeyCode::A,
+                    keyboard::KeyCode::D,
+                ],
+                [
+                    keyboard::KeyCode::Up,
+                    keyboard::KeyCode::Down,
+                    keyboard::KeyCode::Left,
+                    keyboard::KeyCode::Right,
+                ],
+                [
+                    keyboard::KeyCode::I,
+                    keyboard::KeyCode::K,
+                    keyboard::KeyCode::J,
+                    keyboard::KeyCode::L,
+                ],
+                [
+                    keyboard::KeyCode::Numpad8,
+                    keyboard::KeyCode::Numpad5,
+                    keyboard::KeyCode::Numpad4,
+                    keyboard::KeyCode::Numpad6,
+                ],
+            ];
+            for (player, [up, down, left, right]) in keys.iter().enumerate() {
+                bindings
+                    .bind(Input::Key(*up), Action::MovePlayer(player, Direction::Up))
+                    .bind(
+                        Input::Key(*down),
+                        Action::MovePlayer(player, Direction::Down),
+                    )
+                    .bind(
+                        Input::Key(*left),
+                        Action::MovePlayer(player, Direction::Left),
+                    )
+                    .bind(
+                        Input::Key(*right),
+                        Action::MovePlayer(player, Direction::Right),
+                    );
+            }
+        }
+        bindings
+    }
+
+    /// Bind the input to the action, replacing the previous binding of the input.
+    pub fn bind(&mut self, input: Input, action: Action) -> &mut Self {
+        self.bindings.insert(input, action);
+        self
+    }
+
+    /// Get the action that is bound to the input.
+    pub fn action(&self, input: Input) -> Option<Action> {
+        self.bindings.get(&input).copied()
+    }
+}
+
+/// Queue of actions that are fed by all input sources and consumed by the game's systems in
+/// first-in-first-out order.
 #[derive(Default)]
+pub struct ActionQueue {
+    pub queue: std::collections::VecDeque<Action>,
+}
+
+/// The actions that were queued since the level was loaded, so that the session can be played
+/// back. The actions are recorded with the first tick that sees them in the queue, counted from
+/// the load of the level, along with the modes that the level was loaded with.
+#[derive(Debug, Default)]
+pub struct ActionLog {
+    pub start: u64,
+    pub animation_mode: AnimationMode,
+    pub control_mode: ControlMode,
+    pub actions: Vec<(u64, Action)>,
+}
+
+impl ActionLog {
+    /// Start recording a session whose first tick follows the given tick.
+    pub fn new(start: u64, animation_mode: AnimationMode, control_mode: ControlMode) -> Self {
+        Self {
+            start,
+            animation_mode,
+            control_mode,
+            actions: Vec::new(),
+        }
+    }
+
+    /// Record the action that is first seen in the queue at the given tick of the game.
+    pub fn record(&mut self, tick: u64, action: Action) {
+        self.actions.push((tick.saturating_sub(self.start), action));
+    }
+}
+
+/// Determines how many queued actions are consumed in a single simulation step.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AnimationMode {
+    /// One action is consumed per simulation step, so that each move is displayed.
+    #[default]
+    Animated,
+    /// All queued actions are consumed at once.
+    Instant,
+}
+
+impl AnimationMode {
+    pub fn toggle(&mut self) {
+        *self = match self {
+            AnimationMode::Animated => AnimationMode::Instant,
+            AnimationMode::Instant => AnimationMode::Animated,
+        };
+    }
+}
+
+/// The rules by which the level is played.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum Rules {
+    /// The players push the boxes onto the box spots.
+    #[default]
+    Forward,
+    /// The players start from the solved position and pull the boxes back to where they start
+    /// in the level, ending where they can walk back to their starting positions.
+    Reverse { starts: Vec<(u8, u8)> },
+}
+
+/// Determines how the player characters of a level are controlled.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ControlMode {
+    /// The moves are made by the active player, and the active player is switched in turn.
+    #[default]
+    Switching,
+    /// Each player has its own bindings, in addition to the moves of the active player.
+    Cooperative,
+}
+
+impl ControlMode {
+    pub fn toggle(&mut self) {
+        *self = match self {
+            ControlMode::Switching => ControlMode::Cooperative,
+            ControlMode::Cooperative => ControlMode::Switching,
+        };
+    }
+}
+
+/// The control of the player characters, along with the index of the player that is moved by
+/// the move actions.
+#[derive(Debug, Default)]
+pub struct PlayerControl {
+    pub mode: ControlMode,
+    pub active: usize,
+}
+
+/// Configuration of how actions are repeated while their inputs are held down. The action is
+/// first repeated after `delay` has passed, then it is repeated once every `interval`.
+#[derive(Debug)]
+pub struct InputRepeat {
+    pub delay: time::Duration,
+    pub interval: time::Duration,
+}
+
+impl Default for InputRepeat {
+    fn default() -> Self {
+        Self {
+            delay: time::Duration::from_millis(300),
+            interval: time::Duration::from_millis(120),
+        }
+    }
+}
+
+/// Inputs that are currently held down, along with the game time at which their bound actions
+/// are next repeated.
+#[derive(Debug, Default)]
+pub struct HeldInputs {
+    inputs: std::collections::HashMap<Input, time::Duration>,
+}
+
+impl HeldInputs {
+    /// Mark the input as held down. Pressing an input that is already held has no effect.
+    pub fn press(&mut self, input: Input, repeat_at: time::Duration) {
+        self.inputs.entry(input).or_insert(repeat_at);
+    }
+
+    /// Mark the input as released.
+    pub fn release(&mut self, input: Input) {
+        self.inputs.remove(&input);
+    }
+
+    /// Check if the input is being held down.
+    pub fn is_held(&self, input: Input) -> bool {
+        self.inputs.contains_key(&input)
+    }
+
+    /// Get all inputs that are due to be repeated at the given game time.
+    pub fn due(
+        &mut self,
+        now: time::Duration,
+    ) -> impl Iterator<Item = (&Input, &mut time::Duration)> {
+        self.inputs
+            .iter_mut()
+            .filter(move |(_, repeat_at)| **repeat_at <= now)
+    }
+}
+
+/// A move that was made by the player, along with all the entities that were moved by it.
+#[derive(Debug)]
+pub struct Move {
+    pub direction: Direction,
+    /// The player that made the move, followed by the boxes that it pushed or pulled.
+    pub entities: Vec<legion::Entity>,
+    pub displacements: Vec<Displacement>,
+    /// The changes that the move made to the entities besides moving them.
+    pub changes: Vec<Change>,
+}
+
+/// The positions of an entity before and after a move, which are more than one tile apart when
+/// the entity slid on ice or was teleported.
+#[derive(Debug, Clone, Copy)]
+pub struct Displacement {
+    pub entity: legion::Entity,
+    pub from: (u8, u8),
+    pub to: (u8, u8),
+}
+
+/// A change that a move made to an entity besides moving it.
+#[derive(Debug, Clone, Copy)]
+pub enum Change {
+    /// A box fell into a hole and filled it.
+    FillHole {
+        hole: legion::Entity,
+        the_box: legion::Entity,
+    },
+    /// A player picked up a key.
+    PickUpKey {
+        key: legion::Entity,
+        player: legion::Entity,
+    },
+    /// A locked door was opened with a key.
+    Unlock {
+        door: legion::Entity,
+        key: legion::Entity,
+    },
+}
+
+/// History of the moves that were made, which allows moves to be undone and redone.
+#[derive(Debug, Default)]
+pub struct MoveHistory {
+    pub done: Vec<Move>,
+    pub undone: Vec<Move>,
+}
+
+impl MoveHistory {
+    /// Count the moves that pushed a box, that is the moves that moved more than the player.
+    pub fn pushes(&self) -> usize {
+        self.done.iter().filter(|m| m.entities.len() > 1).count()
+    }
+
+    /// Write the moves that were made in the LURD format.
+    pub fn to_lurd(&self) -> String {
+        self.done
+            .iter()
+            .map(|m| m.direction.lurd(m.entities.len() > 1))
+            .collect()
+    }
+}
+
+/// The game map as a dense grid of cells, each with the entities that are on it, along with the
+/// shape of the cells. The grid is indexed from the world once, then kept up to date as the
+/// entities move, so that what is on a cell is found without querying the whole world.
+#[derive(Debug, Default)]
+pub struct Grid {
+    topology: topology::Topology,
+    width: u8,
+    height: u8,
+    cells: Vec<Vec<legion::Entity>>,
+    is_indexed: bool,
+}
+
+impl Grid {
+    /// Create a grid of the given topology that is indexed from the world at the next step.
+    pub fn new(topology: topology::Topology) -> Self {
+        Self {
+            topology,
+            ..Self::default()
+        }
+    }
+
+    pub fn topology(&self) -> topology::Topology {
+        self.topology
+    }
+
+    pub fn is_indexed(&self) -> bool {
+        self.is_indexed
+    }
+
+    /// Put every entity of the world with a position on its cell.
+    pub fn index<W: legion::EntityStore>(&mut self, world: &W) {
+        use legion::IntoQuery;
+
+        let entities = <(legion::Entity, &components::Position)>::query()
+            .iter(world)
+            .map(|(entity, position)| (*entity, (position.x, position.y)))
+            .collect::<Vec<_>>();
+        self.width = 0;
+        self.height = 0;
+        self.cells.clear();
+        for (entity, position) in entities {
+            self.insert(entity, position);
+        }
+        self.is_indexed = true;
+    }
+
+    /// Get the entities on the cell at the position.
+    pub fn entities_at(&self, (x, y): (u8, u8)) -> &[legion::Entity] {
+        if x >= self.width || y >= self.height {
+            return &[];
+        }
+        &self.cells[y as usize * self.width as usize + x as usize]
+    }
+
+    /// Move the entity from the cell where it was to the cell where it is now.
+    pub fn move_entity(&mut self, entity: legion::Entity, from: (u8, u8), to: (u8, u8)) {
+        if from.0 < self.width && from.1 < self.height {
+            let idx = from.1 as usize * self.width as usize + from.0 as usize;
+            self.cells[idx].retain(|other| *other != entity);
+        }
+        self.insert(entity, to);
+    }
+
+    /// Put the entity on the cell at the position, growing the grid if the cell is outside of it.
+    fn insert(&mut self, entity: legion::Entity, (x, y): (u8, u8)) {
+        if x >= self.width || y >= self.height {
+            let width = self.width.max(x.saturating_add(1));
+            let height = self.height.max(y.saturating_add(1));
+            let mut cells = vec![Vec::new(); width as usize * height as usize];
+            for (idx, cell) in self.cells.drain(..).enumerate() {
+                let (cx, cy) = (idx % self.width as usize, idx / self.width as usize);
+                cells[cy * width as usize + cx] = cell;
+            }
+            self.width = width;
+            self.height = height;
+            self.cells = cells;
+        }
+        self.cells[y as usize * self.width as usize + x as usize].push(entity);
+    }
+}
+
+/// How much a volume changes at a time.
+pub const VOLUME_STEP: f32 = 0.1;
+
+/// The volumes of the game's audio, from silent at 0 to full volume at 1. The volumes of the
+/// effects and of the music are scaled by the master volume, and muting silences everything
+/// without losing the volumes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Volume {
+    pub master: f32,
+    pub effects: f32,
+    pub music: f32,
+    pub is_muted: bool,
+}
+
+impl Default for Volume {
+    fn default() -> Self {
+        Self {
+            master: 1.0,
+            effects: 1.0,
+            music: 0.4,
+            is_muted: false,
+        }
+    }
+}
+
+impl Volume {
+    /// Get the volume at which the effects are played.
+    pub fn effects(&self) -> f32 {
+        if self.is_muted {
+            0.0
+        } else {
+            self.master * self.effects
+        }
+    }
+
+    /// Get the volume at which the music is played.
+    pub fn music(&self) -> f32 {
+        if self.is_muted {
+            0.0
+        } else {
+            self.master * self.music
+        }
+    }
+
+    /// Change the master volume by the given amount, keeping it between 0 and 1.
+    pub fn change_master(&mut self, by: f32) {
+        self.master = (self.master + by).clamp(0.0, 1.0);
+    }
+
+    /// Change the volume of the effects by the given amount, keeping it between 0 and 1.
+    pub fn change_effects(&mut self, by: f32) {
+        self.effects = (self.effects + by).clamp(0.0, 1.0);
+    }
+
+    /// Change the volume of the music by the given amount, keeping it between 0 and 1.
+    pub fn change_music(&mut self, by: f32) {
+        self.music = (self.music + by).clamp(0.0, 1.0);
+    }
+}
+
+/// The events of the game that can make a sound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SoundEvent {
+    HitObstacle,
+    /// A box was moved onto a box spot of a matching color.
+    BoxOnMatchingSpot,
+    /// A box was moved onto a box spot of another color.
+    BoxOnOtherSpot,
+    DoorsSwitched,
+    KeyPickedUp,
+    DoorUnlocked,
+}
+
+impl SoundEvent {
+    /// Get the event with the given name in the audio configuration, see [`AudioConfig`].
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "hit_obstacle" => Some(SoundEvent::HitObstacle),
+            "box_on_matching_spot" => Some(SoundEvent::BoxOnMatchingSpot),
+            "box_on_other_spot" => Some(SoundEvent::BoxOnOtherSpot),
+            "doors_switched" => Some(SoundEvent::DoorsSwitched),
+            "key_picked_up" => Some(SoundEvent::KeyPickedUp),
+            "door_unlocked" => Some(SoundEvent::DoorUnlocked),
+            _ => None,
+        }
+    }
+}
+
+/// A sound effect, which is played at its own volume relative to the volume of the effects.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sound {
+    pub path: String,
+    pub volume: f32,
+}
+
+/// The table that maps the events of the game to the sounds that they play. Events without a
+/// sound are silent.
+#[derive(Debug, Clone)]
+pub struct SoundBindings {
+    sounds: std::collections::HashMap<SoundEvent, Sound>,
+}
+
+impl Default for SoundBindings {
+    fn default() -> Self {
+        let mut bindings = Self::empty();
+        bindings
+            .bind(SoundEvent::HitObstacle, "/sounds/wall.wav", 1.0)
+            .bind(SoundEvent::BoxOnMatchingSpot, "/sounds/correct.wav", 1.0)
+            .bind(SoundEvent::BoxOnOtherSpot, "/sounds/incorrect.wav", 1.0)
+            .bind(SoundEvent::DoorsSwitched, "/sounds/door.wav", 1.0)
+            .bind(SoundEvent::KeyPickedUp, "/sounds/key.wav", 1.0)
+            .bind(SoundEvent::DoorUnlocked, "/sounds/unlock.wav", 1.0);
+        bindings
+    }
+}
+
+impl SoundBindings {
+    /// Create a table without any sounds.
+    pub fn empty() -> Self {
+        Self {
+            sounds: std::collections::HashMap::new(),
+        }
+    }
+
+    /// Play the sound at the given path and volume for the event, replacing its previous sound.
+    pub fn bind(&mut self, event: SoundEvent, path: &str, volume: f32) -> &mut Self {
+        self.sounds.insert(
+            event,
+            Sound {
+                path: path.to_string(),
+                volume,
+            },
+        );
+        self
+    }
+
+    /// Silence the event.
+    pub fn unbind(&mut self, event: SoundEvent) -> &mut Self {
+        self.sounds.remove(&event);
+        self
+    }
+
+    /// Get the sound that is played for the event.
+    pub fn sound(&self, event: SoundEvent) -> Option<&Sound> {
+        self.sounds.get(&event)
+    }
+
+    /// Get the paths of the sounds in the table, without duplicates.
+    pub fn paths(&self) -> Vec<&str> {
+        let mut paths = self
+            .sounds
+            .values()
+            .map(|sound| sound.path.as_str())
+            .collect::<Vec<_>>();
+        paths.sort_unstable();
+        paths.dedup();
+        paths
+    }
+}
+
+/// The sounds of the events and the volumes that the game starts with, which are read from the
+/// audio configuration file. The settings that the file does not mention keep their defaults.
+#[derive(Debug, Clone, Default)]
+pub struct AudioConfig {
+    pub sounds: SoundBindings,
+    pub volume: Volume,
+}
+
+impl AudioConfig {
+    /// Read the configuration from its text, with one setting per line. A `sound` line binds an
+    /// event to a sound and an optional volume, or silences it with `none`. A `volume` line sets
+    /// the `master`, `effects` or `music` volume. Empty lines and lines starting with `#` are
+    /// ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```txt
+    /// volume music 0.2
+    /// sound hit_obstacle /sounds/wall.wav 0.5
+    /// sound key_picked_up none
+    /// ```
+    pub fn from_text(text: &str) -> ggez::GameResult<Self> {
+        let mut config = Self::default();
+        for line in text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        {
+            let invalid =
+                || ggez::GameError::ConfigError(format!("Invalid audio setting {}", line));
+            let parse_volume = |volume: Option<&str>| {
+                volume
+                    .map_or(Ok(1.0), str::parse::<f32>)
+                    .ok()
+                    .filter(|volume| (0.0..=1.0).contains(volume))
+                    .ok_or_else(invalid)
+            };
+            let words = line.split_whitespace().collect::<Vec<_>>();
+            match words.as_slice() {
+                ["volume", "master", volume] => config.volume.master = parse_volume(Some(volume))?,
+                ["volume", "effects", volume] => {
+                    config.volume.effects = parse_volume(Some(volume))?
+                }
+                ["volume", "music", volume] => config.volume.music = parse_volume(Some(volume))?,
+                ["sound", event, "none"] => {
+                    config
+                        .sounds
+                        .unbind(SoundEvent::from_name(event).ok_or_else(invalid)?);
+                }
+                ["sound", event, path, volume @ ..] if volume.len() <= 1 => {
+                    let event = SoundEvent::from_name(event).ok_or_else(invalid)?;
+                    let volume = parse_volume(volume.first().copied())?;
+                    config.sounds.bind(event, path, volume);
+                }
+                _ => return Err(invalid()),
+            }
+        }
+        Ok(config)
+    }
+}
+
+/// The game's sound effects and music, which are played through the audio backend at the
+/// configured volumes. The default store plays nothing. A sound that fails to play is skipped
+/// from then on, so that its error is only reported once.
 pub struct AudioStore {
-    sounds: std::collections::HashMap<String, audio::Source>,
+    backend: Box<dyn audio_backend::AudioBackend>,
+    volume: Volume,
+    failed_sounds: std::collections::HashSet<String>,
+}
+
+impl Default for AudioStore {
+    fn default() -> Self {
+        Self::new(audio_backend::Output::Null)
+    }
 }
 
 impl AudioStore {
+    /// Create a store that plays the audio to the output.
+    pub fn new(output: audio_backend::Output) -> Self {
+        Self {
+            backend: output.backend(),
+            volume: Volume::default(),
+            failed_sounds: std::collections::HashSet::new(),
+        }
+    }
+
+    /// Get the backend that plays the audio, if it is of the given type.
+    pub fn backend<B: audio_backend::AudioBackend + 'static>(&self) -> Option<&B> {
+        self.backend.as_any().downcast_ref()
+    }
+
     pub fn add_sound(&mut self, ctx: &mut ggez::Context, sound_path: &str) -> ggez::GameResult {
-        let sound_source = audio::Source::new(ctx, sound_path)?;
-        self.sounds.insert(sound_path.to_string(), sound_source);
-        Ok(())
+        self.backend.add_sound(ctx, sound_path)
     }
 
-    pub fn play_sound(&mut self, sound_path: &str) {
-        if let Some(sound) = self.sounds.get_mut(sound_path) {
-            if sound.play_detached().is_err() {}
+    /// Play the sound at the volume of the effects.
+    pub fn play_sound(&mut self, sound: &Sound) -> ggez::GameResult {
+        let volume = self.volume.effects() * sound.volume;
+        if volume <= 0.0 || self.failed_sounds.contains(&sound.path) {
+            return Ok(());
         }
+        let result = self.backend.play_sound(&sound.path, volume);
+        if result.is_err() {
+            self.failed_sounds.insert(sound.path.clone());
+        }
+        result
+    }
+
+    /// Load the music that is played in the background, in a loop.
+    pub fn add_music(&mut self, ctx: &mut ggez::Context, music_path: &str) -> ggez::GameResult {
+        self.backend.add_music(ctx, music_path)
+    }
+
+    /// Start the music from its beginning.
+    pub fn play_music(&mut self) -> ggez::GameResult {
+        self.backend.play_music(self.volume.music())
+    }
+
+    pub fn volume(&self) -> Volume {
+        self.volume
+    }
+
+    /// Change the volumes, which applies to the music that is playing and to the sounds that are
+    /// played next.
+    pub fn set_volume(&mut self, volume: Volume) {
+        self.volume = volume;
+        self.backend.set_music_volume(volume.music());
     }
 }
 
@@ -97,3 +1202,161 @@ impl DrawableStore {
         self.images.get(image_path)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOUND_EVENTS: [SoundEvent; 6] = [
+        SoundEvent::HitObstacle,
+        SoundEvent::BoxOnMatchingSpot,
+        SoundEvent::BoxOnOtherSpot,
+        SoundEvent::DoorsSwitched,
+        SoundEvent::KeyPickedUp,
+        SoundEvent::DoorUnlocked,
+    ];
+
+    #[test]
+    fn grid_indexes_the_entities_of_the_world() {
+        let mut world = legion::World::default();
+        let wall = world.push((components::Position { x: 2, y: 1, z: 0 },));
+        let the_box = world.push((components::Position { x: 2, y: 1, z: 10 },));
+        let player = world.push((components::Position { x: 0, y: 3, z: 10 },));
+
+        let mut grid = Grid::new(topology::Topology::Hexagonal);
+        assert!(!grid.is_indexed());
+        grid.index(&world);
+        assert!(grid.is_indexed());
+        assert_eq!(grid.topology(), topology::Topology::Hexagonal);
+        assert_eq!(grid.entities_at((2, 1)), &[wall, the_box]);
+        assert_eq!(grid.entities_at((0, 3)), &[player]);
+        assert!(grid.entities_at((1, 1)).is_empty());
+        assert!(grid.entities_at((9, 9)).is_empty());
+    }
+
+    #[test]
+    fn grid_moves_entities_between_cells() {
+        let mut world = legion::World::default();
+        let the_box = world.push((components::Position { x: 2, y: 1, z: 10 },));
+        let player = world.push((components::Position { x: 1, y: 1, z: 10 },));
+        let mut grid = Grid::default();
+        grid.index(&world);
+
+        // Moving onto an occupied cell keeps what is already there.
+        grid.move_entity(player, (1, 1), (2, 1));
+        assert!(grid.entities_at((1, 1)).is_empty());
+        assert_eq!(grid.entities_at((2, 1)), &[the_box, player]);
+
+        // The grid grows to take the cells past its edges, and keeps the cells it had.
+        grid.move_entity(the_box, (2, 1), (5, 4));
+        assert_eq!(grid.entities_at((2, 1)), &[player]);
+        assert_eq!(grid.entities_at((5, 4)), &[the_box]);
+    }
+
+    #[test]
+    fn challenge_is_broken_once_its_budget_is_used() {
+        let challenge = Challenge {
+            max_moves: Some(3),
+            max_pushes: Some(2),
+            time_limit: Some(time::Duration::from_secs(10)),
+            no_undo: false,
+        };
+        let second = time::Duration::from_secs(1);
+        assert!(challenge.broken(2, 1, 9 * second).is_none());
+        assert!(matches!(
+            challenge.broken(3, 1, 9 * second),
+            Some(LossReason::OutOfMoves)
+        ));
+        assert!(matches!(
+            challenge.broken(4, 1, 9 * second),
+            Some(LossReason::OutOfMoves)
+        ));
+        assert!(matches!(
+            challenge.broken(2, 2, 9 * second),
+            Some(LossReason::OutOfPushes)
+        ));
+        assert!(matches!(
+            challenge.broken(2, 1, 10 * second),
+            Some(LossReason::OutOfTime)
+        ));
+        assert!(Challenge::default()
+            .broken(100, 100, 100 * second)
+            .is_none());
+    }
+
+    #[test]
+    fn challenge_without_undo_refuses_undo_and_redo() {
+        let challenge = Challenge {
+            no_undo: true,
+            ..Challenge::default()
+        };
+        let playing = GamePlayState::Playing;
+        assert!(!challenge.allows(Action::Undo, &playing));
+        assert!(!challenge.allows(Action::Redo, &playing));
+        assert!(challenge.allows(Action::Restart, &playing));
+        assert!(challenge.allows(Action::MoveRight, &playing));
+        assert!(Challenge::default().allows(Action::Undo, &playing));
+        assert!(Challenge::default().allows(Action::Redo, &playing));
+    }
+
+    #[test]
+    fn lost_game_only_allows_undo_and_restart() {
+        let lost = GamePlayState::Lost(LossReason::OutOfMoves);
+        let challenge = Challenge::default();
+        assert!(challenge.allows(Action::Undo, &lost));
+        assert!(challenge.allows(Action::Restart, &lost));
+        assert!(!challenge.allows(Action::Redo, &lost));
+        assert!(!challenge.allows(Action::MoveRight, &lost));
+    }
+
+    #[test]
+    fn shipped_audio_config_is_the_default() {
+        let config = AudioConfig::from_text(include_str!("../resources/audio.txt"))
+            .expect("The configuration is valid");
+        let default = AudioConfig::default();
+        assert_eq!(config.volume, default.volume);
+        for event in SOUND_EVENTS {
+            assert_eq!(config.sounds.sound(event), default.sounds.sound(event));
+        }
+    }
+
+    #[test]
+    fn audio_config_overrides_the_default() {
+        let config = AudioConfig::from_text(
+            "# Quieter\nvolume music 0.2\n\nsound hit_obstacle /sounds/door.wav 0.5\n\
+             sound key_picked_up none\nsound door_unlocked /sounds/key.wav\n",
+        )
+        .expect("The configuration is valid");
+        assert_eq!(config.volume.music, 0.2);
+        assert_eq!(config.volume.master, Volume::default().master);
+        assert_eq!(
+            config.sounds.sound(SoundEvent::HitObstacle),
+            Some(&Sound {
+                path: "/sounds/door.wav".to_string(),
+                volume: 0.5,
+            })
+        );
+        assert_eq!(config.sounds.sound(SoundEvent::KeyPickedUp), None);
+        assert_eq!(
+            config
+                .sounds
+                .sound(SoundEvent::DoorUnlocked)
+                .map(|sound| sound.volume),
+            Some(1.0)
+        );
+    }
+
+    #[test]
+    fn invalid_audio_config_is_rejected() {
+        for text in [
+            "volume music loud",
+            "volume music 2",
+            "volume voices 0.5",
+            "sound thunder /sounds/door.wav",
+            "sound hit_obstacle /sounds/door.wav 0.5 0.5",
+            "sound hit_obstacle",
+        ] {
+            assert!(AudioConfig::from_text(text).is_err(), "{}", text);
+        }
+    }
+}
@@ -0,0 +1,249 @@
+use std::collections;
+
+use crate::level;
+use crate::resources;
+use crate::state;
+
+/// A solution of a level.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solution {
+    /// The moves in the LURD format, where lowercase letters are moves and uppercase letters are
+    /// pushes.
+    pub moves: String,
+    pub pushes: usize,
+}
+
+/// How a search for the solution of a level ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The solution with the fewest pushes was found.
+    Solved(Solution),
+    /// The level has no solution, or the search gave up before finding one.
+    Unsolved,
+    /// The level has special floors, which the search does not model, so it was not searched.
+    Unsupported,
+}
+
+/// The outcome of searching for the solution of a level.
+#[derive(Debug, Clone)]
+pub struct Search {
+    pub outcome: Outcome,
+    /// The number of positions that were expanded during the search.
+    pub nodes_expanded: usize,
+}
+
+impl Search {
+    pub fn solution(&self) -> Option<&Solution> {
+        match &self.outcome {
+            Outcome::Solved(solution) => Some(solution),
+            _ => None,
+        }
+    }
+}
+
+/// Search for the solution of the level that takes the fewest pushes. The search is a breadth
+/// first search over the positions of the boxes, where boxes are never pushed onto squares from
+/// which they can not reach a box spot that matches their color. The search gives up after
+/// expanding the given number of positions. Only the first player in reading order moves, the
+/// other players stay where they are like walls. Levels with special floors are not searched,
+/// since the moves on these floors are not modeled. The pushes follow the topology of the level.
+pub fn solve(level: &level::Level, node_limit: usize) -> Search {
+    let mut solver = Solver::new(level, node_limit);
+    loop {
+        if let Some(search) = solver.run(node_limit) {
+            return search;
+        }
+    }
+}
+
+/// A search for the solution of a level, see [`solve`], that is run a few positions at a time so
+/// that it can be spread over the steps of the game.
+#[derive(Debug)]
+pub struct Solver {
+    nodes: Vec<Node>,
+    visited: collections::HashSet<state::State>,
+    /// The positions that are still to be expanded.
+    queue: collections::VecDeque<usize>,
+    nodes_expanded: usize,
+    node_limit: usize,
+    /// The outcome of the search, once it is over.
+    outcome: Option<Search>,
+}
+
+impl Solver {
+    /// Start searching for the solution of the level, expanding at most the given number of
+    /// positions in total.
+    pub fn new(level: &level::Level, node_limit: usize) -> Self {
+        let mut solver = Self {
+            nodes: Vec::new(),
+            visited: collections::HashSet::new(),
+            queue: collections::VecDeque::new(),
+            nodes_expanded: 0,
+            node_limit,
+            outcome: None,
+        };
+        if level.tiles().any(|(_, tile)| tile.special.is_some()) {
+            solver.outcome = Some(Search {
+                outcome: Outcome::Unsupported,
+                nodes_expanded: 0,
+            });
+            return solver;
+        }
+        let root = match state::State::from_level(level) {
+            Some(state) => state,
+            None => {
+                solver.outcome = Some(Search {
+                    outcome: Outcome::Unsolved,
+                    nodes_expanded: 0,
+                });
+                return solver;
+            }
+        };
+
+        let is_solved = root.is_solved();
+        solver.visited.insert(root.normalized());
+        solver.nodes.push(Node {
+            parent: None,
+            state: root,
+        });
+        if is_solved {
+            solver.outcome = Some(Search {
+                outcome: Outcome::Solved(solution(&solver.nodes, 0)),
+                nodes_expanded: 0,
+            });
+        } else {
+            solver.queue.push_back(0);
+        }
+        solver
+    }
+
+    /// Expand at most the given number of positions, and get the outcome of the search once it is
+    /// over.
+    pub fn run(&mut self, budget: usize) -> Option<Search> {
+        for _ in 0..budget {
+            if self.outcome.is_some() {
+                break;
+            }
+            self.expand();
+        }
+        self.outcome.clone()
+    }
+
+    /// Expand the next position in the queue, and end the search when a solution is found or when
+    /// there is nothing left to expand.
+    fn expand(&mut self) {
+        let idx = match self.queue.pop_front() {
+            Some(idx) if self.nodes_expanded < self.node_limit => idx,
+            _ => {
+                self.outcome = Some(Search {
+                    outcome: Outcome::Unsolved,
+                    nodes_expanded: self.nodes_expanded,
+                });
+                return;
+            }
+        };
+        self.nodes_expanded += 1;
+
+        let current = &self.nodes[idx].state;
+        let reachable = current.reachable();
+        let mut children = Vec::new();
+        for (cell, color) in current.boxes() {
+            for direction in current.topology().directions() {
+                let (behind, target) = match (
+                    current.step(cell, direction.opposite()),
+                    current.step(cell, *direction),
+                ) {
+                    (Some(behind), Some(target)) => (behind, target),
+                    _ => continue,
+                };
+                if !reachable.contains(behind)
+                    || current.box_at(target).is_some()
+                    || !current.is_live(target, color)
+                {
+                    continue;
+                }
+
+                let mut state = current.clone();
+                state.push(cell, *direction);
+                if !self.visited.insert(state.normalized()) {
+                    continue;
+                }
+                children.push(Node {
+                    parent: Some((idx, cell, *direction)),
+                    state,
+                });
+            }
+        }
+
+        for node in children {
+            let is_solved = node.state.is_solved();
+            self.nodes.push(node);
+            if is_solved {
+                self.outcome = Some(Search {
+                    outcome: Outcome::Solved(solution(&self.nodes, self.nodes.len() - 1)),
+                    nodes_expanded: self.nodes_expanded,
+                });
+                return;
+            }
+            self.queue.push_back(self.nodes.len() - 1);
+        }
+    }
+}
+
+/// Convert the moves of a reverse game into a forward solution of the level, both in the LURD
+/// format. The reverse game starts from the solved position, and ends with the boxes where they
+/// start in the level and the player at the given position. Going forward, the player walks from
+/// its start to that position, then makes the moves of the reverse game backwards, where the
+/// pulls become pushes. Only levels with a single player are supported.
+pub fn forward_solution(
+    level: &level::Level,
+    end: (u8, u8),
+    reverse_moves: &str,
+) -> Option<String> {
+    if level.tiles().filter(|(_, tile)| tile.player).count() != 1 {
+        return None;
+    }
+    let state = state::State::from_level(level)?;
+
+    let mut moves = state.walk(state.cell(end))?;
+    for symbol in reverse_moves.chars().rev() {
+        let direction = resources::Direction::from_lurd(symbol)?;
+        moves.push(direction.opposite().lurd(symbol.is_ascii_uppercase()));
+    }
+    Some(moves)
+}
+
+/// A position that was reached during the search.
+#[derive(Debug)]
+struct Node {
+    /// The previous position, the box that was pushed from it, and the direction of the push.
+    parent: Option<(usize, usize, resources::Direction)>,
+    state: state::State,
+}
+
+/// Replay the pushes that lead to the node, and join them with the walks of the player in
+/// between.
+fn solution(nodes: &[Node], idx: usize) -> Solution {
+    let mut pushes = Vec::new();
+    let mut current = idx;
+    while let Some((parent, cell, direction)) = nodes[current].parent {
+        pushes.push((cell, direction));
+        current = parent;
+    }
+    pushes.reverse();
+
+    let mut state = nodes[current].state.clone();
+    let mut moves = String::new();
+    for (cell, direction) in pushes.iter() {
+        if let Some(behind) = state.step(*cell, direction.opposite()) {
+            moves.push_str(&state.walk(behind).unwrap_or_default());
+            moves.push(direction.lurd(true));
+            state.push(*cell, *direction);
+        }
+    }
+
+    Solution {
+        moves,
+        pushes: pushes.len(),
+    }
+}
@@ -0,0 +1,549 @@
+use std::collections;
+use std::hash;
+use std::sync;
+
+use crate::components;
+use crate::level;
+use crate::resources;
+use crate::topology;
+
+/// A set of cells of a board, stored as one bit per cell.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Bitset {
+    words: Vec<u64>,
+}
+
+impl Bitset {
+    /// Create an empty set that can hold the given number of cells.
+    pub fn new(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(64)],
+        }
+    }
+
+    pub fn contains(&self, cell: usize) -> bool {
+        self.words
+            .get(cell / 64)
+            .is_some_and(|word| word & (1 << (cell % 64)) != 0)
+    }
+
+    pub fn insert(&mut self, cell: usize) {
+        self.words[cell / 64] |= 1 << (cell % 64);
+    }
+
+    pub fn remove(&mut self, cell: usize) {
+        self.words[cell / 64] &= !(1 << (cell % 64));
+    }
+
+    pub fn len(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|word| *word == 0)
+    }
+
+    /// Get the cells in the set, in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(idx, word)| {
+            let mut word = *word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(idx * 64 + bit)
+            })
+        })
+    }
+}
+
+/// The part of a position that never changes, shared between all the positions of a level.
+#[derive(Debug)]
+struct Board {
+    /// The level without its boxes and players, which holds the floors that the position does not
+    /// track.
+    level: level::Level,
+    /// The cells that can not be moved onto. Only plain floor can be moved onto.
+    walls: Bitset,
+    /// The colors of the boxes, which index the sets of boxes of a position.
+    colors: Vec<components::BoxColor>,
+    box_spots: Vec<(components::BoxColor, Bitset)>,
+    /// For every color of box, the cells from which the box can still be pushed onto a box spot.
+    live: Vec<Bitset>,
+    /// The random keys of the boxes of every color on every cell, then of the player on every
+    /// cell, which are combined into the hash of a position.
+    box_keys: Vec<Vec<u64>>,
+    player_keys: Vec<u64>,
+}
+
+/// A position of a level in a compact form, which is cheap to copy and to compare, for searching
+/// and simulating the game outside of the world. The position tracks a single player, and the
+/// cells of a level are indexed in reading order.
+///
+/// The position is hashed with Zobrist hashing, where every box and player placement has a random
+/// key and the hash is the combination of the keys of the placements. The hash is updated along
+/// with the moves, instead of being computed anew.
+#[derive(Debug, Clone)]
+pub struct State {
+    board: sync::Arc<Board>,
+    /// The boxes of every color of the board.
+    boxes: Vec<Bitset>,
+    player: usize,
+    hash: u64,
+}
+
+impl State {
+    /// Create the position at the start of the level, if the level has a player. Only the first
+    /// player in reading order moves, the other players stay where they are like walls. Special
+    /// floors are walls too.
+    pub fn from_level(level: &level::Level) -> Option<Self> {
+        let ((x, y), _) = level.tiles().find(|(_, tile)| tile.player)?;
+        let level = level.with_single_player(x, y);
+        let cells = level.width() as usize * level.height() as usize;
+
+        let mut colors = level
+            .tiles()
+            .filter_map(|(_, tile)| tile.the_box)
+            .collect::<Vec<_>>();
+        colors.sort_unstable();
+        colors.dedup();
+
+        let mut walls = Bitset::new(cells);
+        let mut box_spots = Vec::<(components::BoxColor, Bitset)>::new();
+        for (idx, (_, tile)) in level.tiles().enumerate() {
+            if tile.terrain != level::Terrain::Floor || tile.special.is_some() {
+                walls.insert(idx);
+            }
+            if let Some(color) = tile.box_spot {
+                match box_spots
+                    .iter_mut()
+                    .find(|(spot_color, _)| *spot_color == color)
+                {
+                    Some((_, spots)) => spots.insert(idx),
+                    None => {
+                        let mut spots = Bitset::new(cells);
+                        spots.insert(idx);
+                        box_spots.push((color, spots));
+                    }
+                }
+            }
+        }
+
+        let live = colors
+            .iter()
+            .map(|color| {
+                let mut live = Bitset::new(cells);
+                for (x, y) in level.live_squares(*color) {
+                    live.insert(y as usize * level.width() as usize + x as usize);
+                }
+                live
+            })
+            .collect();
+
+        let mut keys = (1..).map(zobrist_key);
+        let box_keys = colors
+            .iter()
+            .map(|_| keys.by_ref().take(cells).collect())
+            .collect();
+        let player_keys = keys.take(cells).collect();
+
+        let mut empty = level.clone();
+        for ((x, y), _) in level.tiles() {
+            let tile = empty.get_mut(x, y);
+            tile.the_box = None;
+            tile.player = false;
+        }
+
+        let board = sync::Arc::new(Board {
+            level: empty,
+            walls,
+            colors,
+            box_spots,
+            live,
+            box_keys,
+            player_keys,
+        });
+        let player = y as usize * level.width() as usize + x as usize;
+        let mut state = Self {
+            boxes: vec![Bitset::new(cells); board.colors.len()],
+            player,
+            hash: board.player_keys[player],
+            board,
+        };
+        for ((x, y), tile) in level.tiles() {
+            if let Some(color) = tile.the_box {
+                state.put_box(state.cell((x, y)), color);
+            }
+        }
+        Some(state)
+    }
+
+    /// Create the level where the position is the start.
+    pub fn to_level(&self) -> level::Level {
+        let mut level = self.board.level.clone();
+        for (cell, color) in self.boxes() {
+            let (x, y) = self.position(cell);
+            level.get_mut(x, y).the_box = Some(color);
+        }
+        let (x, y) = self.position(self.player);
+        level.get_mut(x, y).player = true;
+        level
+    }
+
+    pub fn width(&self) -> u8 {
+        self.board.level.width()
+    }
+
+    pub fn height(&self) -> u8 {
+        self.board.level.height()
+    }
+
+    pub fn topology(&self) -> topology::Topology {
+        self.board.level.topology()
+    }
+
+    /// Get the Zobrist hash of the position.
+    pub fn hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// Get the index of the cell at the position.
+    pub fn cell(&self, (x, y): (u8, u8)) -> usize {
+        y as usize * self.width() as usize + x as usize
+    }
+
+    /// Get the position of the cell with the index.
+    pub fn position(&self, cell: usize) -> (u8, u8) {
+        let width = self.width() as usize;
+        ((cell % width) as u8, (cell / width) as u8)
+    }
+
+    pub fn player(&self) -> usize {
+        self.player
+    }
+
+    /// Get the boxes along with their colors, in the order of their cells.
+    pub fn boxes(&self) -> Vec<(usize, components::BoxColor)> {
+        let mut boxes = self
+            .boxes
+            .iter()
+            .zip(self.board.colors.iter())
+            .flat_map(|(cells, color)| cells.iter().map(move |cell| (cell, *color)))
+            .collect::<Vec<_>>();
+        boxes.sort_unstable();
+        boxes
+    }
+
+    pub fn box_at(&self, cell: usize) -> Option<components::BoxColor> {
+        self.boxes
+            .iter()
+            .position(|cells| cells.contains(cell))
+            .map(|idx| self.board.colors[idx])
+    }
+
+    pub fn box_spot_at(&self, cell: usize) -> Option<components::BoxColor> {
+        self.board
+            .box_spots
+            .iter()
+            .find(|(_, spots)| spots.contains(cell))
+            .map(|(color, _)| *color)
+    }
+
+    pub fn is_wall(&self, cell: usize) -> bool {
+        self.board.walls.contains(cell)
+    }
+
+    /// Check if a box of the color on the cell can still be pushed onto a box spot that matches
+    /// its color.
+    pub fn is_live(&self, cell: usize, color: components::BoxColor) -> bool {
+        self.board
+            .colors
+            .iter()
+            .position(|other| *other == color)
+            .is_some_and(|idx| self.board.live[idx].contains(cell))
+    }
+
+    /// Check if every box spot holds a box of a matching color.
+    pub fn is_solved(&self) -> bool {
+        self.board.box_spots.iter().all(|(spot_color, spots)| {
+            spots.iter().all(|cell| {
+                self.box_at(cell)
+                    .is_some_and(|color| color.matches(*spot_color))
+            })
+        })
+    }
+
+    /// Get the cell next to the given cell in the direction, if it is not a wall.
+    pub fn step(&self, cell: usize, direction: resources::Direction) -> Option<usize> {
+        let (x, y) = self.topology().neighbor(self.position(cell), direction)?;
+        if x >= self.width() || y >= self.height() {
+            return None;
+        }
+        let next = self.cell((x, y));
+        if self.is_wall(next) {
+            None
+        } else {
+            Some(next)
+        }
+    }
+
+    /// Move the player in the direction, pushing the box in front of it. Returns whether a box was
+    /// pushed, or `None` if the move is blocked, in which case the position does not change.
+    pub fn apply(&mut self, direction: resources::Direction) -> Option<bool> {
+        let next = self.step(self.player, direction)?;
+        let is_push = match self.box_at(next) {
+            Some(color) => {
+                let target = self
+                    .step(next, direction)
+                    .filter(|target| self.box_at(*target).is_none())?;
+                self.take_box(next, color);
+                self.put_box(target, color);
+                true
+            }
+            None => false,
+        };
+        self.place_player(next);
+        Some(is_push)
+    }
+
+    /// Move the player in the direction, pulling the box behind it along if `is_pulling`, which
+    /// undoes a move. Returns whether a box was pulled, or `None` if the move is blocked, in which
+    /// case the position does not change.
+    pub fn pull(&mut self, direction: resources::Direction, is_pulling: bool) -> Option<bool> {
+        let next = self
+            .step(self.player, direction)
+            .filter(|next| self.box_at(*next).is_none())?;
+        let pulled = if is_pulling {
+            self.step(self.player, direction.opposite())
+                .and_then(|behind| self.box_at(behind).map(|color| (behind, color)))
+        } else {
+            None
+        };
+        if let Some((behind, color)) = pulled {
+            self.take_box(behind, color);
+            self.put_box(self.player, color);
+        }
+        self.place_player(next);
+        Some(pulled.is_some())
+    }
+
+    /// Make the moves in the LURD format, where uppercase letters must push a box and lowercase
+    /// letters must not. Returns whether all the moves could be made, the position is left at the
+    /// first move that could not.
+    pub fn play(&mut self, moves: &str) -> bool {
+        for symbol in moves.chars() {
+            let direction = match resources::Direction::from_lurd(symbol) {
+                Some(direction) => direction,
+                None => return false,
+            };
+            let is_push = self
+                .step(self.player, direction)
+                .is_some_and(|next| self.box_at(next).is_some());
+            if is_push != symbol.is_ascii_uppercase() || self.apply(direction).is_none() {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Push the box on the cell in the direction, with the player taking its place. The player
+    /// must be able to walk behind the box, and the box must be able to move.
+    pub fn push(&mut self, cell: usize, direction: resources::Direction) {
+        if let (Some(color), Some(target)) = (self.box_at(cell), self.step(cell, direction)) {
+            self.take_box(cell, color);
+            self.put_box(target, color);
+            self.place_player(cell);
+        }
+    }
+
+    /// Get the cells that the player can walk to without pushing any box.
+    pub fn reachable(&self) -> Bitset {
+        let mut reachable = Bitset::new(self.width() as usize * self.height() as usize);
+        let mut stack = vec![self.player];
+        while let Some(cell) = stack.pop() {
+            if reachable.contains(cell) {
+                continue;
+            }
+            reachable.insert(cell);
+            for direction in self.topology().directions() {
+                if let Some(next) = self.step(cell, *direction) {
+                    if self.box_at(next).is_none() {
+                        stack.push(next);
+                    }
+                }
+            }
+        }
+        reachable
+    }
+
+    /// Get a copy of the position where the player stands on the top-left cell that it can walk
+    /// to, so that positions that only differ by where the player is standing in the same area
+    /// are equal.
+    pub fn normalized(&self) -> Self {
+        let mut state = self.clone();
+        if let Some(cell) = self.reachable().iter().next() {
+            state.place_player(cell);
+        }
+        state
+    }
+
+    /// Get the shortest walk for the player to the cell without pushing any box in the LURD
+    /// format, if the player can walk there.
+    pub fn walk(&self, to: usize) -> Option<String> {
+        let mut previous = collections::HashMap::new();
+        let mut queue = collections::VecDeque::new();
+        previous.insert(self.player, None);
+        queue.push_back(self.player);
+        while let Some(cell) = queue.pop_front() {
+            if cell == to {
+                break;
+            }
+            for direction in self.topology().directions() {
+                if let Some(next) = self.step(cell, *direction) {
+                    if self.box_at(next).is_none() && !previous.contains_key(&next) {
+                        previous.insert(next, Some((cell, direction.lurd(false))));
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+
+        if !previous.contains_key(&to) {
+            return None;
+        }
+
+        let mut path = Vec::new();
+        let mut cell = to;
+        while let Some(Some((prev, symbol))) = previous.get(&cell) {
+            path.push(*symbol);
+            cell = *prev;
+        }
+        Some(path.into_iter().rev().collect())
+    }
+
+    /// Get the index of the set of boxes of the color. Only the boxes of the level are ever put
+    /// or taken, and the board has the colors of all of them.
+    fn color_idx(&self, color: components::BoxColor) -> usize {
+        self.board
+            .colors
+            .iter()
+            .position(|other| *other == color)
+            .expect("The board has the colors of all the boxes of its level")
+    }
+
+    fn put_box(&mut self, cell: usize, color: components::BoxColor) {
+        let idx = self.color_idx(color);
+        self.boxes[idx].insert(cell);
+        self.hash ^= self.board.box_keys[idx][cell];
+    }
+
+    fn take_box(&mut self, cell: usize, color: components::BoxColor) {
+        let idx = self.color_idx(color);
+        self.boxes[idx].remove(cell);
+        self.hash ^= self.board.box_keys[idx][cell];
+    }
+
+    fn place_player(&mut self, cell: usize) {
+        self.hash ^= self.board.player_keys[self.player] ^ self.board.player_keys[cell];
+        self.player = cell;
+    }
+}
+
+impl PartialEq for State {
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash && self.player == other.player && self.boxes == other.boxes
+    }
+}
+
+impl Eq for State {}
+
+impl hash::Hash for State {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash);
+    }
+}
+
+/// Get the random key with the given index, which is the same across platforms and versions.
+fn zobrist_key(idx: u64) -> u64 {
+    let mut z = idx.wrapping_mul(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use resources::Direction;
+
+    const XSB: &str = "\
+#######
+#     #
+# $$. #
+# @ . #
+#######
+";
+
+    fn state(xsb: &str) -> State {
+        State::from_level(&level::Level::from_test_xsb(xsb)).expect("The level has a player")
+    }
+
+    /// Check that the hash that was updated along with the moves is the hash of the same
+    /// position built anew.
+    fn assert_hash_is_fresh(state: &State) {
+        let fresh = State::from_level(&state.to_level()).expect("The level has a player");
+        assert_eq!(state.hash(), fresh.hash());
+        assert_eq!(*state, fresh);
+    }
+
+    #[test]
+    fn hash_follows_moves() {
+        let mut state = state(XSB);
+        let start = state.hash();
+        assert_eq!(state.apply(Direction::Up), Some(true));
+        assert_hash_is_fresh(&state);
+        assert_eq!(state.apply(Direction::Down), Some(false));
+        assert_eq!(state.apply(Direction::Right), Some(false));
+        assert_hash_is_fresh(&state);
+        assert_eq!(state.apply(Direction::Up), Some(true));
+        assert_hash_is_fresh(&state);
+        assert_eq!(state.apply(Direction::Up), None);
+        assert_hash_is_fresh(&state);
+        assert_ne!(state.hash(), start);
+    }
+
+    #[test]
+    fn hash_follows_pushes_and_pulls() {
+        let mut state = state(XSB);
+        let start = state.hash();
+        state.push(state.cell((2, 2)), Direction::Up);
+        assert_hash_is_fresh(&state);
+        assert!(state.box_at(state.cell((2, 1))).is_some());
+
+        assert_eq!(state.pull(Direction::Down, true), Some(true));
+        assert_hash_is_fresh(&state);
+        assert_eq!(state.hash(), start);
+        assert_eq!(state.pull(Direction::Down, true), None);
+        assert_eq!(state.pull(Direction::Right, true), Some(false));
+        assert_hash_is_fresh(&state);
+        assert_eq!(state.pull(Direction::Up, false), None);
+        assert_ne!(state.hash(), start);
+    }
+
+    #[test]
+    fn play_checks_pushes() {
+        let state = state("######\n#@$ .#\n######\n");
+        assert!(!state.clone().play("rR"));
+        assert!(!state.clone().play("Rr"));
+        assert!(!state.clone().play("Rx"));
+
+        let mut solved = state.clone();
+        assert!(solved.play("RR"));
+        assert!(solved.is_solved());
+    }
+}
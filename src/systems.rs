@@ -1,18 +1,123 @@
 use ggez::graphics;
 use ggez::graphics::spritebatch;
-use ggez::input::keyboard;
 use ggez::mint;
 use itertools::Itertools;
 use legion::query::IntoQuery;
 use legion::system;
 use legion::world::EntityStore;
 
-use std::cmp;
 use std::collections;
 
 use crate::components;
-use crate::game::{MAP_HEIGHT, MAP_WIDTH, TILE_HEIGHT, TILE_WIDTH};
+use crate::game::{ARENA_WIDTH, MAP_HEIGHT, MAP_WIDTH, TILE_HEIGHT, TILE_WIDTH};
+use crate::level;
 use crate::resources;
+use crate::solver;
+use crate::topology;
+
+/// The number of positions that the solver may expand when looking for a hint.
+const HINT_NODE_LIMIT: usize = 100_000;
+
+/// The number of positions that the solver expands at every step while looking for a hint.
+const HINT_NODES_PER_TICK: usize = 2_000;
+
+/// The colors of the walls and floors of triangular grids, which match the images of the walls
+/// and floors of the other grids.
+const TRIANGLE_WALL_COLOR: (u8, u8, u8) = (56, 56, 56);
+const TRIANGLE_FLOOR_COLOR: (u8, u8, u8) = (213, 213, 213);
+const TRIANGLE_OUTLINE_COLOR: (u8, u8, u8) = (160, 160, 160);
+
+/// The area of the screen where the game map is drawn, given by the position of its top-left
+/// corner and the size of its tiles.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    pub origin: mint::Point2<f32>,
+    pub tile_width: f32,
+    pub tile_height: f32,
+}
+
+impl Default for Viewport {
+    fn default() -> Self {
+        Self {
+            origin: mint::Point2 { x: 0.0, y: 0.0 },
+            tile_width: TILE_WIDTH,
+            tile_height: TILE_HEIGHT,
+        }
+    }
+}
+
+impl Viewport {
+    /// Get the area of the screen where the objects on the cell at the position are drawn.
+    /// Hexagonal cells are drawn as tiles laid like bricks, half a tile apart in the doubled
+    /// columns. The objects on triangular cells are drawn at half the size of a tile around the
+    /// center of their triangle.
+    pub fn cell_rect(&self, topology: topology::Topology, x: u8, y: u8) -> graphics::Rect {
+        let (w, h) = (self.tile_width, self.tile_height);
+        let (left, top) = (self.origin.x, self.origin.y + y as f32 * h);
+        match topology {
+            topology::Topology::Square => graphics::Rect::new(left + x as f32 * w, top, w, h),
+            topology::Topology::Hexagonal => {
+                graphics::Rect::new(left + x as f32 * w / 2.0, top, w, h)
+            }
+            topology::Topology::Triangular => {
+                let center_x = left + (x as f32 + 1.0) * w / 2.0;
+                let center_y = if x % 2 == y % 2 {
+                    top + h * 2.0 / 3.0
+                } else {
+                    top + h / 3.0
+                };
+                graphics::Rect::new(center_x - w / 4.0, center_y - h / 4.0, w / 2.0, h / 2.0)
+            }
+        }
+    }
+
+    /// Get the corners of the triangular cell at the position on the screen. Every triangle is a
+    /// tile wide, and its neighbors on the left and right overlap it by half a tile.
+    pub fn triangle(&self, x: u8, y: u8) -> [mint::Point2<f32>; 3] {
+        let (w, h) = (self.tile_width, self.tile_height);
+        let left = self.origin.x + x as f32 * w / 2.0;
+        let (top, bottom) = (
+            self.origin.y + y as f32 * h,
+            self.origin.y + (y as f32 + 1.0) * h,
+        );
+        let (base, apex) = if x % 2 == y % 2 {
+            (bottom, top)
+        } else {
+            (top, bottom)
+        };
+        [
+            mint::Point2 { x: left, y: base },
+            mint::Point2 {
+                x: left + w,
+                y: base,
+            },
+            mint::Point2 {
+                x: left + w / 2.0,
+                y: apex,
+            },
+        ]
+    }
+}
+
+/// The sprite batches of the entities that never move nor change how they are drawn, such as the
+/// floors, the walls, and the box spots, so that they are not batched anew at every frame. The
+/// batches are built again when the cache is invalidated, or when they are drawn for another world
+/// or viewport than the one that they were built for. The cache must be invalidated when the
+/// entities of a world are replaced without replacing the world.
+#[derive(Default)]
+pub struct RenderCache {
+    built_for: Option<(legion::world::WorldId, Viewport, topology::Topology)>,
+    /// The sprite batches of every z-axis level, from the lowest level.
+    layers: Vec<(u8, Vec<spritebatch::SpriteBatch>)>,
+    /// The walls and floors of triangular grids.
+    triangles: Option<graphics::Mesh>,
+}
+
+impl RenderCache {
+    pub fn invalidate(&mut self) {
+        self.built_for = None;
+    }
+}
 
 /// Draw all renderable entities and information in some resources to screen by creating render batches
 /// from based on the renderable entity's data.
@@ -24,61 +129,118 @@ use crate::resources;
 /// parameters that specify how all the entities, that depend on the resource, are rendered. This ensures:
 /// + Entities with lower z-axis level are rendered first.
 /// + The images are loaded with minimal access to memory.
+///
+/// The entities that are not movable, doors, or keys are batched once and kept in the
+/// `RenderCache`, only the others are batched at every frame.
+///
+/// When the players are switched in turn, the players that are not active are drawn faded. Open
+/// doors are drawn faded as well.
+///
+/// The cells are laid out according to the topology of the level, see `Viewport::cell_rect`. The
+/// images of walls and floors are square, so on triangular grids, they are replaced by triangles
+/// that are drawn below everything else.
 pub fn render_entities(
     ctx: &mut ggez::Context,
     world: &legion::World,
     resources: &legion::Resources,
+    viewport: Viewport,
 ) -> ggez::GameResult {
     if let Some(drawable_store) = resources.get::<resources::DrawableStore>() {
         let time_alive = resources
             .get::<resources::Time>()
-            .and_then(|time| Some(time.alive))
+            .map(|time| time.alive())
             .unwrap_or_default();
 
+        let active_player = resources
+            .get::<resources::PlayerControl>()
+            .filter(|control| control.mode == resources::ControlMode::Switching)
+            .map(|control| control.active);
+        let topology = resources
+            .get::<resources::Grid>()
+            .map(|grid| grid.topology())
+            .unwrap_or_default();
+
+        let mut fetched_cache = resources.get_mut::<RenderCache>();
+        let mut local_cache = RenderCache::default();
+        let cache = fetched_cache.as_deref_mut().unwrap_or(&mut local_cache);
+        let built_for = Some((world.id(), viewport, topology));
+        if cache.built_for != built_for {
+            build_static_layers(ctx, world, &drawable_store, viewport, topology, cache)?;
+            cache.built_for = built_for;
+        }
+
         let mut renderable_batches = collections::HashMap::<
             u8,
             collections::HashMap<String, Vec<graphics::DrawParam>>,
         >::new();
 
-        <(&components::Renderable, &components::Position)>::query()
-            .iter(world)
-            .for_each(|(renderable, position)| {
-                let image_idx = match renderable.kind() {
-                    components::RenderableKind::Static => 0,
-                    components::RenderableKind::Animated => {
-                        ((time_alive.as_millis() % 2000) / 500) as usize
-                    }
-                };
-                let image_path = renderable.path(image_idx);
+        <(
+            &components::Renderable,
+            &components::Position,
+            Option<&components::Player>,
+            Option<&components::Door>,
+            Option<&components::LockedDoor>,
+            Option<&components::Key>,
+        )>::query()
+        .filter(
+            legion::component::<components::Movable>()
+                | legion::component::<components::Door>()
+                | legion::component::<components::LockedDoor>()
+                | legion::component::<components::Key>(),
+        )
+        .iter(world)
+        .for_each(|(renderable, position, player, door, locked_door, key)| {
+            // Opened locked doors and keys that were picked up are not drawn.
+            if locked_door.is_some_and(|locked_door| locked_door.opened_with.is_some())
+                || key.is_some_and(|key| key.held_by.is_some())
+            {
+                return;
+            }
 
-                let draw_dest = mint::Point2 {
-                    x: position.x as f32 * TILE_WIDTH,
-                    y: position.y as f32 * TILE_HEIGHT,
-                };
-                let draw_params = graphics::DrawParam::default().dest(draw_dest);
+            let image_idx = match renderable.kind() {
+                components::RenderableKind::Static => 0,
+                components::RenderableKind::Animated => {
+                    ((time_alive.as_millis() % 2000) / 500) as usize
+                }
+            };
+            let image_path = renderable.path(image_idx);
 
-                renderable_batches
-                    .entry(position.z)
-                    .or_default()
-                    .entry(image_path.to_string())
-                    .or_default()
-                    .push(draw_params);
-            });
+            let mut tint = renderable.tint();
+            if player.is_some_and(|player| active_player.is_some_and(|idx| idx != player.index)) {
+                tint.a *= 0.5;
+            }
+            if door.is_some_and(|door| door.is_open) {
+                tint.a *= 0.25;
+            }
+            renderable_batches
+                .entry(position.z)
+                .or_default()
+                .entry(image_path.to_string())
+                .or_default()
+                .push(cell_draw_param(viewport, topology, position, tint));
+        });
+
+        if let Some(mesh) = cache.triangles.as_ref() {
+            graphics::draw(ctx, mesh, graphics::DrawParam::new())?;
+        }
 
-        for (_z, group) in renderable_batches
+        let levels = cache
+            .layers
             .iter()
-            .sorted_by(|a, b| cmp::Ord::cmp(&a.0, &b.0))
-        {
-            for (image_path, draw_params) in group {
-                if let Some(image) = drawable_store.get_image(image_path) {
-                    let mut sprite_batch = spritebatch::SpriteBatch::new(image.clone());
-                    draw_params.iter().for_each(|p| {
-                        let p = p.scale(mint::Vector2 {
-                            x: TILE_WIDTH / image.width() as f32,
-                            y: TILE_HEIGHT / image.height() as f32,
-                        });
-                        sprite_batch.add(p);
-                    });
+            .map(|(z, _)| *z)
+            .chain(renderable_batches.keys().copied())
+            .sorted()
+            .dedup();
+        for z in levels {
+            if let Some((_, sprite_batches)) = cache.layers.iter().find(|(level, _)| *level == z) {
+                for sprite_batch in sprite_batches {
+                    graphics::draw(ctx, sprite_batch, graphics::DrawParam::new())?;
+                }
+            }
+            for (image_path, draw_params) in renderable_batches.get(&z).into_iter().flatten() {
+                if let Some(sprite_batch) =
+                    sprite_batch(&drawable_store, viewport, image_path, draw_params)
+                {
                     graphics::draw(ctx, &sprite_batch, graphics::DrawParam::new())?;
                 }
             }
@@ -87,6 +249,116 @@ pub fn render_entities(
     Ok(())
 }
 
+/// Batch the entities that are kept in the cache, replacing the batches that it held.
+fn build_static_layers(
+    ctx: &mut ggez::Context,
+    world: &legion::World,
+    drawable_store: &resources::DrawableStore,
+    viewport: Viewport,
+    topology: topology::Topology,
+    cache: &mut RenderCache,
+) -> ggez::GameResult {
+    let mut triangles = Vec::new();
+    let mut renderable_batches =
+        collections::BTreeMap::<u8, collections::BTreeMap<String, Vec<graphics::DrawParam>>>::new();
+
+    <(
+        &components::Renderable,
+        &components::Position,
+        Option<&components::Wall>,
+        Option<&components::Floor>,
+    )>::query()
+    .filter(
+        !legion::component::<components::Movable>()
+            & !legion::component::<components::Door>()
+            & !legion::component::<components::LockedDoor>()
+            & !legion::component::<components::Key>(),
+    )
+    .iter(world)
+    .for_each(|(renderable, position, wall, floor)| {
+        if topology == topology::Topology::Triangular && (wall.is_some() || floor.is_some()) {
+            triangles.push((viewport.triangle(position.x, position.y), wall.is_some()));
+            return;
+        }
+        renderable_batches
+            .entry(position.z)
+            .or_default()
+            .entry(renderable.path(0).to_string())
+            .or_default()
+            .push(cell_draw_param(
+                viewport,
+                topology,
+                position,
+                renderable.tint(),
+            ));
+    });
+
+    cache.triangles = None;
+    if !triangles.is_empty() {
+        let mut mesh = graphics::MeshBuilder::new();
+        for (corners, is_wall) in triangles.iter() {
+            let (fill, outline) = if *is_wall {
+                (TRIANGLE_WALL_COLOR, TRIANGLE_WALL_COLOR)
+            } else {
+                (TRIANGLE_FLOOR_COLOR, TRIANGLE_OUTLINE_COLOR)
+            };
+            mesh.polygon(graphics::DrawMode::fill(), corners, fill.into())?;
+            mesh.polygon(graphics::DrawMode::stroke(1.0), corners, outline.into())?;
+        }
+        cache.triangles = Some(mesh.build(ctx)?);
+    }
+
+    cache.layers = renderable_batches
+        .into_iter()
+        .map(|(z, group)| {
+            let sprite_batches = group
+                .iter()
+                .filter_map(|(image_path, draw_params)| {
+                    sprite_batch(drawable_store, viewport, image_path, draw_params)
+                })
+                .collect();
+            (z, sprite_batches)
+        })
+        .collect();
+    Ok(())
+}
+
+/// Get how the image of an entity at the position is drawn. The size of the image is only known
+/// when it is batched, so the scale is relative to a tile until then.
+fn cell_draw_param(
+    viewport: Viewport,
+    topology: topology::Topology,
+    position: &components::Position,
+    tint: graphics::Color,
+) -> graphics::DrawParam {
+    let rect = viewport.cell_rect(topology, position.x, position.y);
+    graphics::DrawParam::default()
+        .dest(rect.point())
+        .scale(mint::Vector2 {
+            x: rect.w / viewport.tile_width,
+            y: rect.h / viewport.tile_height,
+        })
+        .color(tint)
+}
+
+/// Batch the draws of the image, scaling them from the size of a tile to the size of the image.
+fn sprite_batch(
+    drawable_store: &resources::DrawableStore,
+    viewport: Viewport,
+    image_path: &str,
+    draw_params: &[graphics::DrawParam],
+) -> Option<spritebatch::SpriteBatch> {
+    let image = drawable_store.get_image(image_path)?;
+    let mut sprite_batch = spritebatch::SpriteBatch::new(image.clone());
+    for p in draw_params {
+        sprite_batch.add(p.scale(mint::Vector2 {
+            x: p.scale.x * viewport.tile_width / image.width() as f32,
+            y: p.scale.y * viewport.tile_height / image.height() as f32,
+        }));
+    }
+    Some(sprite_batch)
+}
+
 /// Render the current state of the game and display whether the game's objectives have been accomplished.
 ///
 /// # Examples
@@ -95,6 +367,8 @@ pub fn render_entities(
 /// Playing
 /// Moves: 12
 /// FPS: 44.7
+/// Moves left: 28
+/// Time left: 1:05
 /// ```
 pub fn render_gameplay_data(
     ctx: &mut ggez::Context,
@@ -106,7 +380,7 @@ pub fn render_gameplay_data(
         let txt_gameplay_state =
             graphics::TextFragment::new(game_play.state.to_string()).color(text_color);
         let txt_steps_taken =
-            graphics::TextFragment::new(format!("Moves: {}", game_play.steps_taken.to_string()))
+            graphics::TextFragment::new(format!("Moves: {}", game_play.steps_taken))
                 .color(text_color);
         let txt_fps = graphics::TextFragment::new(format!("FPS: {:.2}", ggez::timer::fps(ctx)))
             .color(text_color);
@@ -121,6 +395,37 @@ pub fn render_gameplay_data(
             .add(graphics::TextFragment::new("\n"))
             // Number of frames per second that the game is rendered at.
             .add(txt_fps);
+        // What is left of the budget of the level's challenge.
+        let pushes = resources
+            .get::<resources::MoveHistory>()
+            .map(|history| history.pushes())
+            .unwrap_or_default();
+        let remaining =
+            game_play
+                .challenge
+                .remaining(game_play.steps_taken, pushes, game_play.time_taken);
+        if !remaining.is_empty() {
+            text.add(graphics::TextFragment::new(format!("\n{}", remaining)).color(text_color));
+        }
+        if game_play.hint.is_some() {
+            text.add(graphics::TextFragment::new("\nLooking for a hint...").color(text_color));
+        }
+        if let Some(message) = &game_play.message {
+            text.add(graphics::TextFragment::new(format!("\n{}", message)).color(text_color));
+        }
+        // Forward solution of a level that was solved in reverse.
+        if let Some(solution) = &game_play.solution {
+            text.add(
+                graphics::TextFragment::new(format!("\nSolution:\n{}", solution)).color(text_color),
+            );
+            text.set_bounds(
+                mint::Point2 {
+                    x: ARENA_WIDTH - TILE_WIDTH * MAP_WIDTH as f32 - 60.0,
+                    y: f32::INFINITY,
+                },
+                graphics::Align::Left,
+            );
+        }
 
         let draw_dest = mint::Point2 {
             x: TILE_WIDTH * MAP_WIDTH as f32 + 50.0,
@@ -133,186 +438,1279 @@ pub fn render_gameplay_data(
     Ok(())
 }
 
-/// Consume key pressed events from queue and modify the player's sprite position
-/// based on the received keycode. If a player pushes a moveable item into an
-/// immovable item, then both the player and the moveable item will not change
-/// position. If a player pushes a moveable item into another moveable item or
-/// an empty position, then the player and all the moveable items will change
-/// position
+/// Search for the hint that was asked for, a few positions at every step, and queue its move
+/// once the solution is found. Hints for positions that can not be solved are dropped.
 #[system]
+pub fn hint_searching(
+    #[resource] gameplay: &mut resources::GamePlay,
+    #[resource] actions: &mut resources::ActionQueue,
+) {
+    let hint = match &mut gameplay.hint {
+        Some(hint) => hint,
+        None => return,
+    };
+    let search = match hint.solver.run(HINT_NODES_PER_TICK) {
+        Some(search) => search,
+        None => return,
+    };
+    let first_move = search
+        .solution()
+        .and_then(|solution| solution.moves.chars().next())
+        .and_then(resources::Direction::from_lurd);
+    if let Some(direction) = first_move {
+        actions
+            .queue
+            .push_front(resources::Action::MovePlayer(hint.player, direction));
+    }
+    gameplay.hint = None;
+}
+
+/// Put the entities of a newly loaded level on the cells of the grid. The grid is then kept up to
+/// date by the moves, so that the other systems look up what is on a cell instead of querying
+/// the whole world.
+#[system]
+#[read_component(components::Position)]
+pub fn grid_indexing(world: &mut legion::world::SubWorld, #[resource] grid: &mut resources::Grid) {
+    if !grid.is_indexed() {
+        grid.index(world);
+    }
+}
+
+/// Queue the actions whose inputs are held down once their repeat time is reached, then schedule
+/// their next repetition based on the configured repeat interval. The repeated actions are
+/// recorded like the pressed ones.
+#[system]
+pub fn input_repeating(
+    #[resource] time: &resources::Time,
+    #[resource] repeat: &resources::InputRepeat,
+    #[resource] bindings: &resources::InputBindings,
+    #[resource] held_inputs: &mut resources::HeldInputs,
+    #[resource] actions: &mut resources::ActionQueue,
+    #[resource] log: &mut resources::ActionLog,
+) {
+    for (input, repeat_at) in held_inputs.due(time.alive()) {
+        if let Some(action) = bindings.action(*input) {
+            actions.queue.push_back(action);
+            log.record(time.ticks, action);
+        }
+        *repeat_at = time.alive() + repeat.interval;
+    }
+}
+
+/// Consume actions from queue in the order they were received and modify the
+/// player's sprite position based on each action. If a player pushes a moveable item into an
+/// immovable item or another player, then both the player and the moveable item will not
+/// change position.
+/// If a player pushes a moveable item into another moveable item or an empty
+/// position, then the player and all the moveable items will change position.
+/// Moves are made by the active player, unless the action names the player to move.
+/// Special floors act on the entities that move onto them, see `Surroundings::resolve`.
+/// Moves are recorded so that they can be undone, redone, or reverted all at
+/// once when the level is restarted. The actions that the level's challenge does not allow are
+/// dropped.
+#[system]
+#[read_component(components::Renderable)]
 #[read_component(components::Player)]
+#[read_component(components::Wall)]
+#[read_component(components::Box)]
+#[read_component(components::BoxSpot)]
 #[read_component(components::Movable)]
 #[read_component(components::Immovable)]
+#[read_component(components::Ice)]
+#[read_component(components::OneWay)]
+#[read_component(components::Teleporter)]
+#[read_component(components::Switch)]
+#[read_component(components::Door)]
+#[write_component(components::Hole)]
+#[write_component(components::LockedDoor)]
+#[write_component(components::Key)]
 #[write_component(components::Position)]
+#[allow(clippy::too_many_arguments)]
 pub fn input_handling(
     world: &mut legion::world::SubWorld,
-    #[resource] key_pressed_events: &mut resources::KeyPressedEventQueue,
+    #[resource] animation_mode: &resources::AnimationMode,
+    #[resource] actions: &mut resources::ActionQueue,
+    #[resource] rules: &resources::Rules,
+    #[resource] grid: &mut resources::Grid,
+    #[resource] control: &mut resources::PlayerControl,
     #[resource] gameplay_events: &mut resources::GamePlayEventQueue,
     #[resource] gameplay: &mut resources::GamePlay,
+    #[resource] history: &mut resources::MoveHistory,
 ) {
-    let mut query_movables =
-        <(&components::Movable, &components::Position, legion::Entity)>::query();
+    // Actions are consumed in the order they were queued. When moves are animated, only one
+    // action is consumed per simulation step so that every move can be seen.
+    while let Some(action) = actions.queue.pop_front() {
+        if !gameplay.challenge.allows(action, &gameplay.state) {
+            continue;
+        }
+        perform_action(
+            world,
+            action,
+            rules,
+            grid,
+            control,
+            gameplay_events,
+            gameplay,
+            history,
+        );
+        if *animation_mode == resources::AnimationMode::Animated {
+            break;
+        }
+    }
+}
 
-    let movables = query_movables
-        .iter(world)
-        .map(|(_m, position, entity)| ((position.x, position.y), *entity))
-        .collect::<collections::HashMap<(u8, u8), legion::Entity>>();
+/// Check if the moves, pushes, or time taken so far break the level's challenge.
+fn is_out_of_budget(gameplay: &resources::GamePlay, history: &resources::MoveHistory) -> bool {
+    gameplay
+        .challenge
+        .broken(gameplay.steps_taken, history.pushes(), gameplay.time_taken)
+        .is_some()
+}
 
-    let mut query_immovables = <(
-        &components::Immovable,
-        &components::Position,
-        legion::Entity,
-    )>::query();
+/// Perform a single action on the game world.
+#[allow(clippy::too_many_arguments)]
+fn perform_action(
+    world: &mut legion::world::SubWorld,
+    action: resources::Action,
+    rules: &resources::Rules,
+    grid: &mut resources::Grid,
+    control: &mut resources::PlayerControl,
+    gameplay_events: &mut resources::GamePlayEventQueue,
+    gameplay: &mut resources::GamePlay,
+    history: &mut resources::MoveHistory,
+) {
+    // A hint is only made in the position that it was asked in.
+    if action != resources::Action::Hint {
+        gameplay.hint = None;
+    }
+    gameplay.message = None;
+    let (player, direction) = match action {
+        resources::Action::SwitchPlayer => {
+            let players = <&components::Player>::query().iter(world).count();
+            if players > 0 {
+                control.active = (control.active + 1) % players;
+            }
+            return;
+        }
+        resources::Action::Undo => {
+            if let Some(last_move) = history.done.pop() {
+                undo_move(world, grid, &last_move);
+                gameplay.steps_taken = gameplay.steps_taken.saturating_sub(1);
+                history.undone.push(last_move);
+            }
+            return;
+        }
+        // Redoing a move spends the budget of the challenge like making it again.
+        resources::Action::Redo if !is_out_of_budget(gameplay, history) => {
+            if let Some(next_move) = history.undone.pop() {
+                apply_move(world, grid, &next_move);
+                gameplay.steps_taken += 1;
+                history.done.push(next_move);
+            }
+            return;
+        }
+        resources::Action::Restart => {
+            while let Some(last_move) = history.done.pop() {
+                undo_move(world, grid, &last_move);
+            }
+            history.undone.clear();
+            gameplay.steps_taken = 0;
+            // The clock of the challenge starts again.
+            gameplay.started_at = None;
+            gameplay.time_taken = std::time::Duration::ZERO;
+            gameplay.state = resources::GamePlayState::Playing;
+            return;
+        }
+        // A hint makes the first move of the active player in the solution of the current
+        // position, where the other players stay where they are. The solver only plays forward,
+        // and searches over the next steps, see `hint_searching`.
+        resources::Action::Hint if *rules == resources::Rules::Forward => {
+            let position = <(&components::Player, &components::Position)>::query()
+                .iter(world)
+                .find(|(player, _)| player.index == control.active)
+                .map(|(_, position)| (position.x, position.y));
+            if let Some((x, y)) = position {
+                let level = level::Level::from_world(world)
+                    .with_topology(grid.topology())
+                    .with_single_player(x, y);
+                gameplay.hint = Some(resources::PendingHint {
+                    player: control.active,
+                    solver: solver::Solver::new(&level, HINT_NODE_LIMIT),
+                });
+            }
+            return;
+        }
+        resources::Action::MovePlayer(player, direction) => (player, direction),
+        action => match action.direction() {
+            Some(direction) => (control.active, direction),
+            None => return,
+        },
+    };
+
+    // The game is only found lost at the end of the step, so the moves that are queued after the
+    // last move of the budget are stopped here.
+    if is_out_of_budget(gameplay, history) {
+        return;
+    }
 
-    let immovables = query_immovables
+    let player_pos = match <(&components::Player, &components::Position)>::query()
         .iter(world)
-        .map(|(_m, position, entity)| ((position.x, position.y), *entity))
-        .collect::<collections::HashMap<(u8, u8), legion::Entity>>();
+        .find(|(p, _)| p.index == player)
+    {
+        Some((_p, position)) => (position.x, position.y),
+        None => return,
+    };
+
+    let mut surroundings = Surroundings::new(world, grid);
+    let to_move = match rules {
+        resources::Rules::Forward => {
+            find_pushed(player_pos, direction, &surroundings, gameplay_events)
+        }
+        resources::Rules::Reverse { .. } => {
+            find_pulled(player_pos, direction, &surroundings, gameplay_events)
+        }
+    };
+
+    if to_move.is_empty() {
+        return;
+    }
+
+    // When pushing, the player is behind the boxes, and when pulling, it is in front of them.
+    let front_first = match rules {
+        resources::Rules::Forward => to_move.iter().rev().copied().collect::<Vec<_>>(),
+        resources::Rules::Reverse { .. } => to_move.clone(),
+    };
+    let (displacements, changes) = match rules {
+        resources::Rules::Forward => surroundings.resolve(&front_first, direction, true),
+        resources::Rules::Reverse { .. } => surroundings.resolve(&front_first, direction, false),
+    };
+    drop(surroundings);
+    let the_move = resources::Move {
+        direction,
+        entities: to_move.into_iter().map(|(entity, _)| entity).collect(),
+        displacements,
+        changes,
+    };
+
+    // Move all entities that can be moved
+    gameplay.steps_taken += 1;
+    apply_move(world, grid, &the_move);
+    for movable in the_move.entities.iter() {
+        gameplay_events
+            .queue
+            .push(resources::GamePlayEvent::EntityMoved(*movable));
+    }
+    for change in the_move.changes.iter() {
+        match change {
+            resources::Change::PickUpKey { .. } => gameplay_events
+                .queue
+                .push(resources::GamePlayEvent::KeyPickedUp),
+            resources::Change::Unlock { .. } => gameplay_events
+                .queue
+                .push(resources::GamePlayEvent::DoorUnlocked),
+            resources::Change::FillHole { .. } => {}
+        }
+    }
+
+    history.undone.clear();
+    history.done.push(the_move);
+}
 
-    // Iterate through all entities starting from the player's position on the game map
-    // and moving along the axis that is defined by the keyboard input, and check for each
-    // entity if it can be moved
-    if let Some(keycode) = key_pressed_events.queue.pop() {
-        let mut to_move = Vec::new();
+/// The entities on the game map that take part in a move, looked up on the cells of the grid,
+/// along with the moves and changes that were made to them while the move is resolved.
+struct Surroundings<'a, W: legion::EntityStore> {
+    world: &'a W,
+    grid: &'a resources::Grid,
+    topology: topology::Topology,
+    /// The movable entities that were moved while resolving the move, by the positions that they
+    /// left or moved onto, along with whether they are players.
+    moved: collections::HashMap<(u8, u8), Option<(legion::Entity, bool)>>,
+    /// The holes that were filled, the locked doors that were opened, and the keys that were
+    /// picked up while resolving the move.
+    used: collections::HashSet<legion::Entity>,
+    /// The groups of the doors whose switches are pressed.
+    pressed: collections::HashSet<u8>,
+    /// The teleporters, along with the position of the other teleporter of their pair.
+    teleporters: collections::HashMap<(u8, u8), (u8, u8)>,
+    /// The keys that the players picked up and did not use yet.
+    spare_keys: collections::HashMap<legion::Entity, Vec<legion::Entity>>,
+}
 
-        <(&components::Player, &components::Position)>::query()
+impl<'a, W: legion::EntityStore> Surroundings<'a, W> {
+    fn new(world: &'a W, grid: &'a resources::Grid) -> Self {
+        let mut surroundings = Self {
+            world,
+            grid,
+            topology: grid.topology(),
+            moved: collections::HashMap::new(),
+            used: collections::HashSet::new(),
+            pressed: collections::HashSet::new(),
+            teleporters: collections::HashMap::new(),
+            spare_keys: collections::HashMap::new(),
+        };
+
+        // The doors block like immovable entities unless a switch of their group is pressed,
+        // or something stands in them.
+        surroundings.pressed = <(&components::Switch, &components::Position)>::query()
             .iter(world)
-            .for_each(|(_p, player_pos)| {
-                // Determine the range and axis to move along base on the input
-                let (start, end, is_xaxis) = match keycode {
-                    keyboard::KeyCode::Up => (player_pos.y, 0, false),
-                    keyboard::KeyCode::Down => (player_pos.y, MAP_HEIGHT, false),
-                    keyboard::KeyCode::Left => (player_pos.x, 0, true),
-                    keyboard::KeyCode::Right => (player_pos.x, MAP_WIDTH, true),
-                    _ => return,
-                };
+            .filter(|(_, position)| surroundings.movable_at((position.x, position.y)).is_some())
+            .map(|(switch, _)| switch.group)
+            .collect();
 
-                let range = if start < end {
-                    (start..=end).collect::<Vec<_>>()
-                } else {
-                    (end..=start).rev().collect::<Vec<_>>()
-                };
+        let used_keys = <&components::LockedDoor>::query()
+            .iter(world)
+            .filter_map(|locked_door| locked_door.opened_with)
+            .collect::<collections::HashSet<_>>();
+        for (key, entity) in <(&components::Key, legion::Entity)>::query().iter(world) {
+            if let Some(player) = key.held_by.filter(|_| !used_keys.contains(entity)) {
+                surroundings
+                    .spare_keys
+                    .entry(player)
+                    .or_default()
+                    .push(*entity);
+            }
+        }
 
-                for x_or_y in range {
-                    let pos = if is_xaxis {
-                        (x_or_y, player_pos.y)
-                    } else {
-                        (player_pos.x, x_or_y)
-                    };
-
-                    match movables.get(&pos) {
-                        // If encounter a movable entity, add it to list of movable entities
-                        Some(movable) => to_move.push(movable),
-                        // Otherwise, check if the entity is immovable
-                        None => {
-                            if immovables.get(&pos).is_some() {
-                                gameplay_events
-                                    .queue
-                                    .push(resources::GamePlayEvent::HitObstacle);
-                                to_move.clear();
-                            }
-                            break;
-                        }
-                    }
+        let mut pairs = collections::HashMap::<u8, Vec<(u8, u8)>>::new();
+        for (teleporter, position) in
+            <(&components::Teleporter, &components::Position)>::query().iter(world)
+        {
+            pairs
+                .entry(teleporter.pair)
+                .or_default()
+                .push((position.x, position.y));
+        }
+        // Teleporters without exactly one partner do nothing.
+        for positions in pairs.values() {
+            if let [first, second] = positions[..] {
+                surroundings.teleporters.insert(first, second);
+                surroundings.teleporters.insert(second, first);
+            }
+        }
+
+        surroundings
+    }
+
+    /// Get the first entity on the cell at the position with a component of the given type for
+    /// which the function returns a value, along with that value.
+    fn find_at<T: legion::storage::Component, R>(
+        &self,
+        position: (u8, u8),
+        f: impl Fn(legion::Entity, &T) -> Option<R>,
+    ) -> Option<R> {
+        self.grid.entities_at(position).iter().find_map(|entity| {
+            let entry = self.world.entry_ref(*entity).ok()?;
+            let component = entry.get_component::<T>().ok()?;
+            f(*entity, component)
+        })
+    }
+
+    fn has<T: legion::storage::Component>(&self, position: (u8, u8)) -> bool {
+        self.find_at::<T, _>(position, |_, _| Some(())).is_some()
+    }
+
+    /// Get the movable entity at the position, along with whether it is a player. The boxes that
+    /// fill holes are not movable anymore.
+    fn movable_at(&self, position: (u8, u8)) -> Option<(legion::Entity, bool)> {
+        if let Some(movable) = self.moved.get(&position) {
+            return *movable;
+        }
+        self.find_at::<components::Movable, _>(position, |entity, _| {
+            let is_sunk = self
+                .find_at::<components::Hole, _>(position, |_, hole| {
+                    hole.filled_by.filter(|the_box| *the_box == entity)
+                })
+                .is_some();
+            let entry = self.world.entry_ref(entity).ok()?;
+            let is_player = entry.get_component::<components::Player>().is_ok();
+            (!is_sunk).then_some((entity, is_player))
+        })
+    }
+
+    /// Check if the position is blocked by an immovable entity, or by a closed door. Whether a
+    /// door is closed is decided by the positions before the move.
+    fn is_immovable(&self, position: (u8, u8)) -> bool {
+        self.has::<components::Immovable>(position)
+            || self
+                .find_at::<components::Door, _>(position, |_, door| {
+                    (!self.pressed.contains(&door.group)).then_some(())
+                })
+                .is_some_and(|_| !self.has::<components::Movable>(position))
+    }
+
+    fn one_way_at(&self, position: (u8, u8)) -> Option<resources::Direction> {
+        self.find_at::<components::OneWay, _>(position, |_, one_way| Some(one_way.direction))
+    }
+
+    /// Get the hole at the position, if it is not filled yet.
+    fn hole_at(&self, position: (u8, u8)) -> Option<legion::Entity> {
+        self.find_at::<components::Hole, _>(position, |entity, hole| {
+            (hole.filled_by.is_none() && !self.used.contains(&entity)).then_some(entity)
+        })
+    }
+
+    /// Get the locked door at the position, if it is not opened yet.
+    fn locked_door_at(&self, position: (u8, u8)) -> Option<legion::Entity> {
+        self.find_at::<components::LockedDoor, _>(position, |entity, locked_door| {
+            (locked_door.opened_with.is_none() && !self.used.contains(&entity)).then_some(entity)
+        })
+    }
+
+    /// Get the key at the position, if it is not picked up yet.
+    fn key_at(&self, position: (u8, u8)) -> Option<legion::Entity> {
+        self.find_at::<components::Key, _>(position, |entity, key| {
+            (key.held_by.is_none() && !self.used.contains(&entity)).then_some(entity)
+        })
+    }
+
+    fn has_spare_key(&self, player: legion::Entity) -> bool {
+        self.spare_keys
+            .get(&player)
+            .is_some_and(|keys| !keys.is_empty())
+    }
+
+    /// Check if the floor lets an entity move from the position onto the next one along the
+    /// direction. One-way floors can only be moved onto and off along their direction.
+    fn can_step(&self, from: (u8, u8), to: (u8, u8), direction: resources::Direction) -> bool {
+        !self.is_immovable(to)
+            && [from, to].iter().all(|position| {
+                self.one_way_at(*position)
+                    .is_none_or(|one_way| one_way == direction)
+            })
+    }
+
+    /// Move the entities, given front first, by one tile along the direction, then let the
+    /// special floors act on them when their effects are enabled. The entities in front move
+    /// first, so that the entities behind them can follow. Players use their keys on the locked
+    /// doors that they walk into, and pick up the keys that they walk onto. Returns where the
+    /// entities were moved from and to, and the other changes that were made to them.
+    fn resolve(
+        &mut self,
+        front_first: &[(legion::Entity, (u8, u8))],
+        direction: resources::Direction,
+        has_effects: bool,
+    ) -> (Vec<resources::Displacement>, Vec<resources::Change>) {
+        let mut displacements = Vec::new();
+        let mut changes = Vec::new();
+        for (entity, from) in front_first {
+            let is_player = match self.movable_at(*from) {
+                Some((_, is_player)) => is_player,
+                None => continue,
+            };
+            self.moved.insert(*from, None);
+            let mut to = self.topology.neighbor(*from, direction).unwrap_or(*from);
+            if has_effects {
+                to = self.land(to, direction, is_player);
+            }
+
+            let hole = self.hole_at(to).filter(|_| has_effects);
+            match hole {
+                Some(hole) => {
+                    self.used.insert(hole);
+                    changes.push(resources::Change::FillHole {
+                        hole,
+                        the_box: *entity,
+                    });
+                }
+                None => {
+                    self.moved.insert(to, Some((*entity, is_player)));
                 }
+            }
+            displacements.push(resources::Displacement {
+                entity: *entity,
+                from: *from,
+                to,
             });
 
-        if !to_move.is_empty() {
-            gameplay.steps_taken += 1;
+            if !is_player || !has_effects {
+                continue;
+            }
+            if let Some(door) = self.locked_door_at(to) {
+                self.used.insert(door);
+                if let Some(key) = self.spare_keys.get_mut(entity).and_then(Vec::pop) {
+                    changes.push(resources::Change::Unlock { door, key });
+                }
+            }
+            if let Some(key) = self.key_at(to) {
+                self.used.insert(key);
+                changes.push(resources::Change::PickUpKey {
+                    key,
+                    player: *entity,
+                });
+                self.spare_keys.entry(*entity).or_default().push(key);
+            }
         }
+        (displacements, changes)
+    }
 
-        // Move all entities that can be moved
-        for movable in to_move {
-            gameplay_events
-                .queue
-                .push(resources::GamePlayEvent::EntityMoved(*movable));
-            if let Ok(mut entry) = world.entry_mut(*movable) {
-                if let Ok(mut position) = entry.get_component_mut::<components::Position>() {
-                    match keycode {
-                        keyboard::KeyCode::Up => position.y = position.y.saturating_sub(1),
-                        keyboard::KeyCode::Down => position.y = position.y.saturating_add(1),
-                        keyboard::KeyCode::Left => position.x = position.x.saturating_sub(1),
-                        keyboard::KeyCode::Right => position.x = position.x.saturating_add(1),
-                        _ => continue,
-                    }
+    /// Get where an entity that moved onto the position along the direction comes to rest.
+    /// Entities on ice slide until they are blocked or leave the ice, and entities on a
+    /// teleporter are taken to the other teleporter of its pair. Boxes stop in holes.
+    fn land(
+        &self,
+        mut position: (u8, u8),
+        direction: resources::Direction,
+        is_player: bool,
+    ) -> (u8, u8) {
+        loop {
+            if self.hole_at(position).is_some() {
+                return position;
+            }
+            if let Some(other) = self.teleporters.get(&position) {
+                if self.movable_at(*other).is_some() {
+                    return position;
+                }
+                return *other;
+            }
+            if !self.has::<components::Ice>(position) {
+                return position;
+            }
+            let next = self.topology.neighbor(position, direction).filter(|next| {
+                self.can_step(position, *next, direction)
+                    && self.movable_at(*next).is_none()
+                    && self.locked_door_at(*next).is_none()
+                    && (!is_player || self.hole_at(*next).is_none())
+            });
+            match next {
+                Some(next) => position = next,
+                None => return position,
+            }
+        }
+    }
+}
+
+/// Find the entities that are moved when the player at the position pushes in the direction,
+/// along with their positions, starting with the player. The player moves along with the line of
+/// movable entities in front of it, unless the line is blocked by an immovable entity, another
+/// player, or a one-way floor. The line ends early when its front box can fall into a hole.
+fn find_pushed(
+    player_pos: (u8, u8),
+    direction: resources::Direction,
+    surroundings: &Surroundings<impl legion::EntityStore>,
+    gameplay_events: &mut resources::GamePlayEventQueue,
+) -> Vec<(legion::Entity, (u8, u8))> {
+    // Walk from the player's position along the direction, and check for each entity in the line
+    // if it can be moved
+    let mut to_move = Vec::new();
+    let mut position = player_pos;
+    let is_blocked = loop {
+        match surroundings.movable_at(position) {
+            // The other players can not be pushed, so they block the move like an immovable
+            // entity
+            Some((_, true)) if !to_move.is_empty() => break true,
+            // If encounter a movable entity, add it to list of movable entities
+            Some((movable, _)) => to_move.push((movable, position)),
+            None => break false,
+        }
+
+        let next = match surroundings.topology.neighbor(position, direction) {
+            Some(next) if surroundings.can_step(position, next, direction) => next,
+            _ => break true,
+        };
+        // Only boxes fall into holes, the players walk around them
+        if surroundings.hole_at(next).is_some() {
+            break to_move.len() == 1;
+        }
+        // Only players with a key walk into locked doors, and boxes can not be pushed into them
+        if surroundings.locked_door_at(next).is_some() {
+            break to_move.len() > 1 || !surroundings.has_spare_key(to_move[0].0);
+        }
+        position = next;
+    };
+
+    if is_blocked {
+        gameplay_events
+            .queue
+            .push(resources::GamePlayEvent::HitObstacle);
+        to_move.clear();
+    }
+    to_move
+}
+
+/// Find the entities that are moved when the player at the position pulls in the direction, along
+/// with their positions, starting with the player. The player moves onto the next position if it
+/// is empty, and the box behind the player follows it. One-way floors are crossed against their
+/// direction, since the moves are made in reverse.
+fn find_pulled(
+    player_pos: (u8, u8),
+    direction: resources::Direction,
+    surroundings: &Surroundings<impl legion::EntityStore>,
+    gameplay_events: &mut resources::GamePlayEventQueue,
+) -> Vec<(legion::Entity, (u8, u8))> {
+    let (player, target) = match (
+        surroundings.movable_at(player_pos),
+        surroundings.topology.neighbor(player_pos, direction),
+    ) {
+        (Some((player, _)), Some(target)) => (player, target),
+        _ => return Vec::new(),
+    };
+    if surroundings.movable_at(target).is_some()
+        || surroundings.hole_at(target).is_some()
+        || surroundings.locked_door_at(target).is_some()
+        || !surroundings.can_step(player_pos, target, direction.opposite())
+    {
+        gameplay_events
+            .queue
+            .push(resources::GamePlayEvent::HitObstacle);
+        return Vec::new();
+    }
+
+    let mut to_move = vec![(player, player_pos)];
+    if let Some(behind) = surroundings
+        .topology
+        .neighbor(player_pos, direction.opposite())
+    {
+        if let Some((the_box, false)) = surroundings.movable_at(behind) {
+            if surroundings.can_step(behind, player_pos, direction.opposite()) {
+                to_move.push((the_box, behind));
+            }
+        }
+    }
+    to_move
+}
+
+/// Put the entities of the move at their positions after the move.
+fn apply_move(
+    world: &mut legion::world::SubWorld,
+    grid: &mut resources::Grid,
+    the_move: &resources::Move,
+) {
+    for displacement in the_move.displacements.iter() {
+        place_entity(world, grid, displacement.entity, displacement.to);
+    }
+    for change in the_move.changes.iter() {
+        apply_change(world, *change, true);
+    }
+}
+
+/// Put the entities of the move back at their positions before the move.
+fn undo_move(
+    world: &mut legion::world::SubWorld,
+    grid: &mut resources::Grid,
+    the_move: &resources::Move,
+) {
+    for displacement in the_move.displacements.iter() {
+        place_entity(world, grid, displacement.entity, displacement.from);
+    }
+    for change in the_move.changes.iter().rev() {
+        apply_change(world, *change, false);
+    }
+}
+
+/// Make the change to the entities, or revert it.
+fn apply_change(world: &mut legion::world::SubWorld, change: resources::Change, is_made: bool) {
+    match change {
+        resources::Change::FillHole { hole, the_box } => fill_hole(world, hole, the_box, is_made),
+        resources::Change::PickUpKey { key, player } => {
+            if let Ok(mut entry) = world.entry_mut(key) {
+                if let Ok(key) = entry.get_component_mut::<components::Key>() {
+                    key.held_by = if is_made { Some(player) } else { None };
                 }
             }
         }
+        resources::Change::Unlock { door, key } => {
+            if let Ok(mut entry) = world.entry_mut(door) {
+                if let Ok(door) = entry.get_component_mut::<components::LockedDoor>() {
+                    door.opened_with = if is_made { Some(key) } else { None };
+                }
+            }
+        }
+    }
+}
+
+/// Put the entity at the position, on the game map and on the grid.
+fn place_entity(
+    world: &mut legion::world::SubWorld,
+    grid: &mut resources::Grid,
+    entity: legion::Entity,
+    (x, y): (u8, u8),
+) {
+    if let Ok(mut entry) = world.entry_mut(entity) {
+        if let Ok(position) = entry.get_component_mut::<components::Position>() {
+            grid.move_entity(entity, (position.x, position.y), (x, y));
+            position.x = x;
+            position.y = y;
+        }
+    }
+}
+
+/// Fill the hole with the box, or empty it. A box that fills a hole is drawn just above the hole,
+/// below the other objects.
+fn fill_hole(
+    world: &mut legion::world::SubWorld,
+    hole: legion::Entity,
+    the_box: legion::Entity,
+    is_filled: bool,
+) {
+    if let Ok(mut entry) = world.entry_mut(hole) {
+        if let Ok(hole) = entry.get_component_mut::<components::Hole>() {
+            hole.filled_by = if is_filled { Some(the_box) } else { None };
+        }
+    }
+    if let Ok(mut entry) = world.entry_mut(the_box) {
+        if let Ok(position) = entry.get_component_mut::<components::Position>() {
+            position.z = if is_filled { 7 } else { 10 };
+        }
+    }
+}
+
+/// Open the doors whose switches are pressed and close the others, so that the doors are drawn
+/// the way that they block the moves.
+#[system]
+#[read_component(components::Player)]
+#[read_component(components::Movable)]
+#[read_component(components::Immovable)]
+#[read_component(components::Ice)]
+#[read_component(components::OneWay)]
+#[read_component(components::Hole)]
+#[read_component(components::Teleporter)]
+#[read_component(components::Switch)]
+#[read_component(components::LockedDoor)]
+#[read_component(components::Key)]
+#[read_component(components::Position)]
+#[write_component(components::Door)]
+pub fn door_switching(
+    world: &mut legion::world::SubWorld,
+    #[resource] grid: &resources::Grid,
+    #[resource] gameplay_events: &mut resources::GamePlayEventQueue,
+) {
+    let surroundings = Surroundings::new(world, grid);
+    let open_doors = <(legion::Entity, &components::Door, &components::Position)>::query()
+        .iter(world)
+        .filter(|(_, _, position)| !surroundings.is_immovable((position.x, position.y)))
+        .map(|(entity, _, _)| *entity)
+        .collect::<collections::HashSet<_>>();
+    drop(surroundings);
+
+    let mut switched = collections::BTreeSet::new();
+    for (entity, door) in <(legion::Entity, &mut components::Door)>::query().iter_mut(world) {
+        let is_open = open_doors.contains(entity);
+        if door.is_open != is_open {
+            door.is_open = is_open;
+            switched.insert((door.group, is_open));
+        }
+    }
+    for (_, is_open) in switched {
+        gameplay_events
+            .queue
+            .push(resources::GamePlayEvent::DoorsSwitched(is_open));
     }
 }
 
-/// Check if all the boxes have been put in their correct position, if so, change the state of the
-/// game from `Playing` to `Won`
+/// Check if all the boxes have been put in their correct position, that is every box spot holds a
+/// box of a matching color, if so, change the state of the game from `Playing` to `Won`. When
+/// playing in reverse, the players must also be able to walk back to where they start.
 #[system]
 #[read_component(components::Box)]
 #[read_component(components::BoxSpot)]
+#[read_component(components::Player)]
+#[read_component(components::Immovable)]
 #[read_component(components::Position)]
 pub fn game_objective(
     world: &mut legion::world::SubWorld,
+    #[resource] rules: &resources::Rules,
+    #[resource] grid: &resources::Grid,
     #[resource] game_play: &mut resources::GamePlay,
 ) {
-    let mut boxes_query = <(&components::Box, &components::Position)>::query();
-    let boxes = boxes_query
-        .iter(world)
-        .map(|(b, position)| ((position.x, position.y), b))
-        .collect::<collections::HashMap<(u8, u8), &components::Box>>();
-
     game_play.state = resources::GamePlayState::Playing;
     let mut box_spots_query = <(&components::BoxSpot, &components::Position)>::query();
     for (box_spot, box_spot_position) in box_spots_query.iter(world) {
-        match boxes.get(&(box_spot_position.x, box_spot_position.y)) {
+        match box_at(world, grid, (box_spot_position.x, box_spot_position.y)) {
             Some(the_box) => {
-                if the_box.color != box_spot.color {
+                if !the_box.color.matches(box_spot.color) {
                     return;
                 }
             }
             None => return,
         }
     }
+    if let resources::Rules::Reverse { starts } = rules {
+        let region = walkable_region(world, grid);
+        if !starts.iter().all(|start| region.contains(start)) {
+            return;
+        }
+    }
     game_play.state = resources::GamePlayState::Won;
 }
 
-/// Consume all events that were generated by the game play
+/// Count the time that is taken to play the level, then lose the game if it is not won and breaks
+/// a constraint of the level's challenge. The state is decided anew at every step after the
+/// objective is checked, so that a move that wins the level on the last of the budget wins.
+#[system]
+pub fn challenge_checking(
+    #[resource] time: &resources::Time,
+    #[resource] history: &resources::MoveHistory,
+    #[resource] game_play: &mut resources::GamePlay,
+) {
+    if matches!(game_play.state, resources::GamePlayState::Won) {
+        return;
+    }
+    let started_at = *game_play.started_at.get_or_insert(time.alive());
+    game_play.time_taken = time.alive().saturating_sub(started_at);
+    if let Some(reason) = game_play.challenge.broken(
+        game_play.steps_taken,
+        history.pushes(),
+        game_play.time_taken,
+    ) {
+        game_play.state = resources::GamePlayState::Lost(reason);
+    }
+}
+
+/// Get the box on the cell at the position, if there is one.
+fn box_at<'a>(
+    world: &'a legion::world::SubWorld,
+    grid: &resources::Grid,
+    position: (u8, u8),
+) -> Option<&'a components::Box> {
+    grid.entities_at(position).iter().find_map(|entity| {
+        world
+            .entry_ref(*entity)
+            .ok()?
+            .into_component::<components::Box>()
+            .ok()
+    })
+}
+
+/// Get the positions that the players can walk to without moving any box.
+fn walkable_region(
+    world: &legion::world::SubWorld,
+    grid: &resources::Grid,
+) -> collections::HashSet<(u8, u8)> {
+    let topology = grid.topology();
+    let is_blocked = |position: (u8, u8)| {
+        grid.entities_at(position).iter().any(|entity| {
+            world.entry_ref(*entity).is_ok_and(|entry| {
+                entry.get_component::<components::Immovable>().is_ok()
+                    || entry.get_component::<components::Box>().is_ok()
+            })
+        })
+    };
+
+    let mut region = collections::HashSet::new();
+    let mut stack = <(&components::Player, &components::Position)>::query()
+        .iter(world)
+        .map(|(_, position)| (position.x, position.y))
+        .collect::<Vec<_>>();
+    while let Some(position) = stack.pop() {
+        if grid.entities_at(position).is_empty() || is_blocked(position) || !region.insert(position)
+        {
+            continue;
+        }
+        for direction in topology.directions() {
+            stack.extend(topology.neighbor(position, *direction));
+        }
+    }
+    region
+}
+
+/// Consume all events that were generated by the game play, playing the sounds that are bound
+/// to them. A sound that can not be played is reported to the player the first time, and is
+/// skipped from then on, see [`resources::AudioStore::play_sound`].
 #[system]
 #[read_component(components::Box)]
 #[read_component(components::BoxSpot)]
 #[read_component(components::Position)]
 pub fn consume_gameplay_events(
     world: &mut legion::world::SubWorld,
+    #[resource] grid: &resources::Grid,
     #[resource] gameplay_events: &mut resources::GamePlayEventQueue,
+    #[resource] sounds: &resources::SoundBindings,
     #[resource] audio_store: &mut resources::AudioStore,
+    #[resource] gameplay: &mut resources::GamePlay,
 ) {
     let mut new_events = Vec::new();
-    gameplay_events.queue.drain(..).for_each(|evt| match evt {
-        resources::GamePlayEvent::HitObstacle => audio_store.play_sound("/sounds/wall.wav"),
-        resources::GamePlayEvent::EntityMoved(entity) => {
-            if let Ok(entry) = world.entry_ref(entity) {
-                if let (Ok(the_box), Ok(box_pos)) = (
-                    entry.get_component::<components::Box>(),
-                    entry.get_component::<components::Position>(),
-                ) {
-                    let mut box_spots_query =
-                        <(&components::BoxSpot, &components::Position)>::query();
-                    box_spots_query
-                        .iter(world)
-                        .for_each(|(box_spot, box_spot_pos)| {
-                            if box_pos.x == box_spot_pos.x && box_pos.y == box_spot_pos.y {
+    for evt in gameplay_events.queue.drain(..) {
+        let sound_event = match evt {
+            resources::GamePlayEvent::HitObstacle => resources::SoundEvent::HitObstacle,
+            resources::GamePlayEvent::EntityMoved(entity) => {
+                if let Ok(entry) = world.entry_ref(entity) {
+                    if let (Ok(the_box), Ok(box_pos)) = (
+                        entry.get_component::<components::Box>(),
+                        entry.get_component::<components::Position>(),
+                    ) {
+                        for entity in grid.entities_at((box_pos.x, box_pos.y)) {
+                            if let Some(box_spot) =
+                                world.entry_ref(*entity).ok().and_then(|entry| {
+                                    entry.into_component::<components::BoxSpot>().ok()
+                                })
+                            {
                                 new_events.push(resources::GamePlayEvent::BoxSpacedOnSpot(
-                                    box_spot.color == the_box.color,
+                                    box_spot.color.matches(the_box.color),
                                 ));
                             }
-                        });
+                        }
+                    }
                 }
+                continue;
+            }
+            resources::GamePlayEvent::DoorsSwitched(_) => resources::SoundEvent::DoorsSwitched,
+            resources::GamePlayEvent::KeyPickedUp => resources::SoundEvent::KeyPickedUp,
+            resources::GamePlayEvent::DoorUnlocked => resources::SoundEvent::DoorUnlocked,
+            resources::GamePlayEvent::BoxSpacedOnSpot(true) => {
+                resources::SoundEvent::BoxOnMatchingSpot
+            }
+            resources::GamePlayEvent::BoxSpacedOnSpot(false) => {
+                resources::SoundEvent::BoxOnOtherSpot
+            }
+        };
+        if let Some(sound) = sounds.sound(sound_event) {
+            if let Err(e) = audio_store.play_sound(sound) {
+                gameplay.message = Some(format!("Could not play the sound {}: {}", sound.path, e));
             }
         }
-        resources::GamePlayEvent::BoxSpacedOnSpot(is_same_color) => {
-            audio_store.play_sound(if is_same_color {
-                "/sounds/correct.wav"
-            } else {
-                "/sounds/incorrect.wav"
-            })
-        }
-    });
+    }
 
     gameplay_events.queue.append(&mut new_events);
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{game, level, resources};
+
+    /// Play the actions on the level, ticking long enough after each one for the world to settle.
+    fn play(xsb: &str, actions: &[resources::Action]) -> game::Headless {
+        let mut game = game::Headless::new(&level::Level::from_test_xsb(xsb));
+        for action in actions {
+            game.queue(*action);
+            game.tick(30);
+        }
+        game
+    }
+
+    /// Check that the actions lead to the expected position, and that undoing them restores the
+    /// level.
+    fn assert_plays_and_undoes(xsb: &str, actions: &[resources::Action], expected: &str) {
+        let mut game = play(xsb, actions);
+        assert_eq!(game.level().to_colored_xsb(), expected);
+        for _ in actions {
+            game.queue(resources::Action::Undo);
+            game.tick(30);
+        }
+        assert_eq!(
+            game.level().to_colored_xsb(),
+            level::Level::from_test_xsb(xsb).to_colored_xsb()
+        );
+    }
+
+    #[test]
+    fn box_slides_on_ice_until_it_leaves_it() {
+        assert_plays_and_undoes(
+            "\
+#########
+#@$~~~ .#
+#########
+",
+            &[resources::Action::MoveRight],
+            "\
+#########
+# @~~~$.#
+#########
+Boxes: blue
+Spots: blue
+",
+        );
+    }
+
+    #[test]
+    fn push_against_a_one_way_floor_is_refused() {
+        let xsb = "\
+########
+#@ $< .#
+########
+";
+        let mut game = play(
+            xsb,
+            &[resources::Action::MoveRight, resources::Action::MoveRight],
+        );
+        assert_eq!(
+            game.level().to_xsb(),
+            "\
+########
+# @$< .#
+########
+"
+        );
+
+        // The refused push is not recorded, so a single undo takes back the walk.
+        game.queue(resources::Action::Undo);
+        game.tick(30);
+        assert_eq!(game.level().to_xsb(), xsb);
+    }
+
+    #[test]
+    fn box_fills_a_hole() {
+        assert_plays_and_undoes(
+            "\
+########
+#@$o$ .#
+########
+",
+            &[resources::Action::MoveRight],
+            "\
+########
+# @ $ .#
+########
+Boxes: blue
+Spots: blue
+",
+        );
+    }
+
+    #[test]
+    fn box_is_teleported_to_the_other_teleporter() {
+        assert_plays_and_undoes(
+            "\
+##########
+#@$T  T .#
+##########
+Teleporters: 1 1
+",
+            &[resources::Action::MoveRight],
+            "\
+##########
+# @T  $ .#
+##########
+Boxes: blue
+Spots: blue
+Specials: - T -
+Teleporters: 1 1
+",
+        );
+    }
+
+    const SWITCH_XSB: &str = "\
+########
+#@$a  .#
+#   $A.#
+########
+";
+
+    #[test]
+    fn door_is_closed_while_its_switch_is_free() {
+        let game = play(
+            SWITCH_XSB,
+            &[
+                resources::Action::MoveDown,
+                resources::Action::MoveRight,
+                resources::Action::MoveRight,
+            ],
+        );
+        assert_eq!(
+            game.level().to_xsb(),
+            "\
+########
+# $a  .#
+#  @$A.#
+########
+"
+        );
+    }
+
+    #[test]
+    fn switch_opens_its_door() {
+        assert_plays_and_undoes(
+            SWITCH_XSB,
+            &[
+                resources::Action::MoveRight,
+                resources::Action::MoveDown,
+                resources::Action::MoveRight,
+                resources::Action::MoveRight,
+            ],
+            "\
+########
+#  $  .#
+#   @$.#
+########
+Boxes: blue blue
+Spots: blue blue
+Specials: a - - A -
+",
+        );
+    }
+
+    const KEY_XSB: &str = "\
+########
+#@ L $.#
+#k######
+########
+";
+
+    #[test]
+    fn key_unlocks_a_door() {
+        assert_plays_and_undoes(
+            KEY_XSB,
+            &[
+                resources::Action::MoveDown,
+                resources::Action::MoveUp,
+                resources::Action::MoveRight,
+                resources::Action::MoveRight,
+            ],
+            "\
+########
+#  @ $.#
+# ######
+########
+Boxes: blue
+Spots: blue
+",
+        );
+    }
+
+    #[test]
+    fn undoing_a_key_pickup_locks_the_door_again() {
+        let mut game = play(
+            KEY_XSB,
+            &[
+                resources::Action::MoveDown,
+                resources::Action::MoveUp,
+                resources::Action::MoveRight,
+                resources::Action::MoveRight,
+                resources::Action::Undo,
+                resources::Action::Undo,
+                resources::Action::Undo,
+                resources::Action::Undo,
+            ],
+        );
+        assert_eq!(game.level().to_xsb(), KEY_XSB);
+
+        // Without the key, the player stops in front of the door.
+        for _ in 0..2 {
+            game.queue(resources::Action::MoveRight);
+            game.tick(30);
+        }
+        assert_eq!(
+            game.level().to_xsb(),
+            "\
+########
+# @L $.#
+#k######
+########
+"
+        );
+    }
+
+    const CHALLENGE_XSB: &str = "\
+#######
+#@ $ .#
+#######
+Move limit: 2
+";
+
+    /// Check if the game is in the state that matches the pattern.
+    macro_rules! assert_state {
+        ($game:expr, $state:pat) => {
+            assert!($game
+                .resources
+                .get::<resources::GamePlay>()
+                .is_some_and(|gameplay| matches!(gameplay.state, $state)))
+        };
+    }
+
+    #[test]
+    fn winning_move_on_the_last_of_the_budget_wins() {
+        let game = play(
+            "\
+######
+#@ $.#
+######
+Move limit: 2
+",
+            &[resources::Action::MoveRight, resources::Action::MoveRight],
+        );
+        assert_state!(game, resources::GamePlayState::Won);
+    }
+
+    #[test]
+    fn moves_past_the_budget_lose_and_are_refused() {
+        let game = play(
+            CHALLENGE_XSB,
+            &[
+                resources::Action::MoveRight,
+                resources::Action::MoveRight,
+                resources::Action::MoveLeft,
+            ],
+        );
+        assert_state!(
+            game,
+            resources::GamePlayState::Lost(resources::LossReason::OutOfMoves)
+        );
+        assert_eq!(
+            game.level().to_xsb(),
+            "\
+#######
+#  @$.#
+#######
+"
+        );
+    }
+
+    #[test]
+    fn redo_after_a_loss_spends_the_budget_again() {
+        let mut game = play(
+            CHALLENGE_XSB,
+            &[
+                resources::Action::MoveRight,
+                resources::Action::MoveRight,
+                resources::Action::Undo,
+            ],
+        );
+        assert_state!(game, resources::GamePlayState::Playing);
+
+        // The redone move is the last of the budget, so the game is lost again.
+        game.queue(resources::Action::Redo);
+        game.tick(30);
+        game.queue(resources::Action::MoveLeft);
+        game.tick(30);
+        assert_state!(
+            game,
+            resources::GamePlayState::Lost(resources::LossReason::OutOfMoves)
+        );
+        assert_eq!(
+            game.level().to_xsb(),
+            "\
+#######
+#  @$.#
+#######
+"
+        );
+    }
+
+    #[test]
+    fn grid_after_undo_matches_a_fresh_index() {
+        let game = play(
+            "\
+#######
+#@$ $.#
+#   . #
+#######
+",
+            &[
+                resources::Action::MoveRight,
+                resources::Action::MoveRight,
+                resources::Action::MoveDown,
+                resources::Action::Undo,
+                resources::Action::MoveDown,
+                resources::Action::Undo,
+                resources::Action::Undo,
+            ],
+        );
+
+        let mut fresh = resources::Grid::default();
+        fresh.index(&game.world);
+        let grid = game
+            .resources
+            .get::<resources::Grid>()
+            .expect("The game has a grid");
+        for y in 0..4 {
+            for x in 0..7 {
+                let entities = |grid: &resources::Grid| {
+                    grid.entities_at((x, y))
+                        .iter()
+                        .copied()
+                        .collect::<std::collections::HashSet<_>>()
+                };
+                assert_eq!(entities(&grid), entities(&fresh), "at ({}, {})", x, y);
+            }
+        }
+    }
+}
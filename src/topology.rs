@@ -0,0 +1,223 @@
+use crate::resources::Direction;
+
+/// The shape of the cells of a level, which decides which cells are next to each other and in
+/// which directions the players move.
+///
+/// Every topology addresses its cells by a column and a row:
+/// + Square cells are next to the cells above, below, left, and right of them.
+/// + Hexagonal cells, as in Hexoban, use doubled columns. The cells of a row are two columns
+///   apart, and the rows are shifted by one column from each other, so that only the positions
+///   where `x + y` is even are cells. A cell is next to the cells on its left and right, and to
+///   the two cells above and the two cells below it.
+/// + Triangular cells, as in Trioban, fill every position of a row and point up when `x + y` is
+///   even, and down otherwise. A cell is next to the cells on its left and right, and to the cell
+///   across its flat side. Moving in a diagonal direction alternates between crossing the flat
+///   side and crossing a slanted side, so that pushed boxes follow a straight line.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Topology {
+    #[default]
+    Square,
+    Hexagonal,
+    Triangular,
+}
+
+const SQUARE_DIRECTIONS: [Direction; 4] = [
+    Direction::Up,
+    Direction::Down,
+    Direction::Left,
+    Direction::Right,
+];
+
+const SIX_DIRECTIONS: [Direction; 6] = [
+    Direction::UpLeft,
+    Direction::UpRight,
+    Direction::Left,
+    Direction::Right,
+    Direction::DownLeft,
+    Direction::DownRight,
+];
+
+impl Topology {
+    /// Get the directions that the players can move in.
+    pub fn directions(self) -> &'static [Direction] {
+        match self {
+            Topology::Square => &SQUARE_DIRECTIONS,
+            Topology::Hexagonal | Topology::Triangular => &SIX_DIRECTIONS,
+        }
+    }
+
+    /// Get the position of the cell next to the given cell in the direction, if the direction
+    /// exists in the topology and the position is not negative.
+    ///
+    /// Triangular cells have three neighbors but six directions. A diagonal direction crosses the
+    /// flat side of the cell when it faces that way, and otherwise the slanted side on the same
+    /// side, so `UpLeft` and `UpRight` lead from a cell pointing up to the same cells as `Left`
+    /// and `Right`, and `DownLeft` and `DownRight` from a cell pointing down. The walks coincide,
+    /// but the pushes do not: the pushed box stands on a cell pointing the other way, so it moves
+    /// on across a flat side, where a push to the left or right keeps it in its row.
+    pub fn neighbor(self, (x, y): (u8, u8), direction: Direction) -> Option<(u8, u8)> {
+        let points_up = x % 2 == y % 2;
+        let (dx, dy) = match (self, direction) {
+            (Topology::Square, Direction::Up) => (0, -1),
+            (Topology::Square, Direction::Down) => (0, 1),
+            (Topology::Square, Direction::Left) => (-1, 0),
+            (Topology::Square, Direction::Right) => (1, 0),
+            (Topology::Square, _) => return None,
+
+            (Topology::Hexagonal, Direction::Left) => (-2, 0),
+            (Topology::Hexagonal, Direction::Right) => (2, 0),
+            (Topology::Hexagonal, Direction::UpLeft) => (-1, -1),
+            (Topology::Hexagonal, Direction::UpRight) => (1, -1),
+            (Topology::Hexagonal, Direction::DownLeft) => (-1, 1),
+            (Topology::Hexagonal, Direction::DownRight) => (1, 1),
+            (Topology::Hexagonal, _) => return None,
+
+            (Topology::Triangular, Direction::Left) => (-1, 0),
+            (Topology::Triangular, Direction::Right) => (1, 0),
+            (Topology::Triangular, Direction::UpLeft) if points_up => (-1, 0),
+            (Topology::Triangular, Direction::UpRight) if points_up => (1, 0),
+            (Topology::Triangular, Direction::UpLeft | Direction::UpRight) => (0, -1),
+            (Topology::Triangular, Direction::DownLeft) if !points_up => (-1, 0),
+            (Topology::Triangular, Direction::DownRight) if !points_up => (1, 0),
+            (Topology::Triangular, Direction::DownLeft | Direction::DownRight) => (0, 1),
+            (Topology::Triangular, _) => return None,
+        };
+        let nx = x as i16 + dx;
+        let ny = y as i16 + dy;
+        if nx < 0 || ny < 0 || nx > u8::MAX as i16 || ny > u8::MAX as i16 {
+            return None;
+        }
+        Some((nx as u8, ny as u8))
+    }
+
+    /// Check if the position is a cell of the topology, rather than a gap between cells.
+    pub fn is_cell(self, x: u8, y: u8) -> bool {
+        match self {
+            Topology::Hexagonal => x % 2 == y % 2,
+            Topology::Square | Topology::Triangular => true,
+        }
+    }
+
+    /// Get the name of the topology, as used in the XSB format.
+    pub fn name(self) -> &'static str {
+        match self {
+            Topology::Square => "square",
+            Topology::Hexagonal => "hexagonal",
+            Topology::Triangular => "triangular",
+        }
+    }
+
+    /// Get the topology with the given name, which is either its name or the name of the variant
+    /// of the game that is played on it.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "square" | "sokoban" => Some(Topology::Square),
+            "hexagonal" | "hexoban" => Some(Topology::Hexagonal),
+            "triangular" | "trioban" => Some(Topology::Triangular),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn square_cells_have_four_neighbors() {
+        let topology = Topology::Square;
+        assert_eq!(topology.neighbor((3, 3), Direction::Up), Some((3, 2)));
+        assert_eq!(topology.neighbor((3, 3), Direction::Down), Some((3, 4)));
+        assert_eq!(topology.neighbor((3, 3), Direction::Left), Some((2, 3)));
+        assert_eq!(topology.neighbor((3, 3), Direction::Right), Some((4, 3)));
+        assert_eq!(topology.neighbor((3, 3), Direction::UpLeft), None);
+        assert_eq!(topology.neighbor((0, 0), Direction::Left), None);
+        assert_eq!(topology.neighbor((0, 0), Direction::Up), None);
+        assert_eq!(topology.neighbor((u8::MAX, 0), Direction::Right), None);
+    }
+
+    #[test]
+    fn hexagonal_cells_have_six_neighbors() {
+        let topology = Topology::Hexagonal;
+        let neighbors = SIX_DIRECTIONS
+            .iter()
+            .map(|direction| topology.neighbor((3, 3), *direction))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            neighbors,
+            vec![
+                Some((2, 2)),
+                Some((4, 2)),
+                Some((1, 3)),
+                Some((5, 3)),
+                Some((2, 4)),
+                Some((4, 4)),
+            ]
+        );
+        assert!(neighbors
+            .iter()
+            .flatten()
+            .all(|(x, y)| topology.is_cell(*x, *y)));
+        assert_eq!(topology.neighbor((3, 3), Direction::Up), None);
+        assert!(!topology.is_cell(2, 3));
+    }
+
+    #[test]
+    fn triangular_diagonals_cross_the_flat_side_when_it_faces_them() {
+        let topology = Topology::Triangular;
+        let neighbors = |position| {
+            SIX_DIRECTIONS
+                .iter()
+                .map(|direction| topology.neighbor(position, *direction))
+                .collect::<Vec<_>>()
+        };
+
+        // (2, 2) points up, so only the downward diagonals cross its flat side.
+        assert_eq!(
+            neighbors((2, 2)),
+            vec![
+                Some((1, 2)),
+                Some((3, 2)),
+                Some((1, 2)),
+                Some((3, 2)),
+                Some((2, 3)),
+                Some((2, 3)),
+            ]
+        );
+        // (3, 2) points down, so only the upward diagonals cross its flat side.
+        assert_eq!(
+            neighbors((3, 2)),
+            vec![
+                Some((3, 1)),
+                Some((3, 1)),
+                Some((2, 2)),
+                Some((4, 2)),
+                Some((2, 2)),
+                Some((4, 2)),
+            ]
+        );
+        assert_eq!(topology.neighbor((3, 3), Direction::Up), None);
+    }
+
+    #[test]
+    fn triangular_diagonal_and_sideways_pushes_differ() {
+        let topology = Topology::Triangular;
+        let push = |direction| {
+            let the_box = topology.neighbor((2, 2), direction)?;
+            topology.neighbor(the_box, direction)
+        };
+        assert_eq!(push(Direction::Left), Some((0, 2)));
+        assert_eq!(push(Direction::UpLeft), Some((1, 1)));
+        assert_eq!(push(Direction::Right), Some((4, 2)));
+        assert_eq!(push(Direction::UpRight), Some((3, 1)));
+    }
+
+    #[test]
+    fn names_round_trip() {
+        for topology in [Topology::Square, Topology::Hexagonal, Topology::Triangular] {
+            assert_eq!(Topology::from_name(topology.name()), Some(topology));
+        }
+        assert_eq!(Topology::from_name("Trioban"), Some(Topology::Triangular));
+        assert_eq!(Topology::from_name("octagonal"), None);
+    }
+}